@@ -0,0 +1,103 @@
+package rfc9839
+
+import (
+	"unicode/utf8"
+)
+
+// AllSubsets reports which of the built-in subsets some text is valid for.
+type AllSubsets struct {
+	Scalars     bool
+	XmlChars    bool
+	Assignables bool
+}
+
+// ValidAllString checks s against Scalars, XmlChars, and Assignables in a single pass.
+func ValidAllString(s string) AllSubsets {
+	// decoded in place, since converting s to a []byte would copy it
+	result := AllSubsets{Scalars: true, XmlChars: true, Assignables: true}
+	index := 0
+	for index < len(s) {
+		r, width := utf8.DecodeRuneInString(s[index:])
+		if r == utf8.RuneError && width == 1 {
+			return AllSubsets{}
+		}
+		if !result.add(r) {
+			break
+		}
+		index += width
+	}
+	return result
+}
+
+// ValidAllUtf8 checks u against Scalars, XmlChars, and Assignables in a single pass.
+func ValidAllUtf8(u []byte) AllSubsets {
+	result := AllSubsets{Scalars: true, XmlChars: true, Assignables: true}
+	index := 0
+	for index < len(u) {
+		r, width := utf8.DecodeRune(u[index:])
+		if r == utf8.RuneError && width == 1 {
+			return AllSubsets{}
+		}
+		if !result.add(r) {
+			break
+		}
+		index += width
+	}
+	return result
+}
+
+// add clears the flags of the subsets which r is not in, and reports whether any are left.
+func (result *AllSubsets) add(r rune) bool {
+	// each flag is checked only until it has been falsified
+	if result.Assignables && !subsetContains(Assignables, r) {
+		result.Assignables = false
+	}
+	if result.XmlChars && !subsetContains(XmlChars, r) {
+		result.XmlChars = false
+	}
+	if result.Scalars && !subsetContains(Scalars, r) {
+		result.Scalars = false
+	}
+	return result.Scalars || result.XmlChars || result.Assignables
+}
+
+// XmlButNotAssignable returns the runes in s which are in XmlChars but not in Assignables,
+// that is, those which would newly be rejected by moving from XmlChars to Assignables.
+// Invalid UTF-8 is in neither subset and is not reported.
+func XmlButNotAssignable(s string) []Violation {
+	var found []Violation
+	index := 0
+	for offset, r := range s {
+		if subsetContains(XmlChars, r) && !subsetContains(Assignables, r) {
+			found = append(found, newViolation(Assignables, offset, index, utf8.RuneLen(r), r))
+		}
+		index++
+	}
+	return found
+}
+
+// CorpusStats counts how many items of a corpus are entirely valid for each built-in subset.
+type CorpusStats struct {
+	Total       int
+	Scalars     int
+	XmlChars    int
+	Assignables int
+}
+
+// CorpusSubsetStats checks each of items against all the built-in subsets.
+func CorpusSubsetStats(items []string) CorpusStats {
+	stats := CorpusStats{Total: len(items)}
+	for _, item := range items {
+		valid := ValidAllString(item)
+		if valid.Scalars {
+			stats.Scalars++
+		}
+		if valid.XmlChars {
+			stats.XmlChars++
+		}
+		if valid.Assignables {
+			stats.Assignables++
+		}
+	}
+	return stats
+}
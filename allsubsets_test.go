@@ -0,0 +1,95 @@
+package rfc9839
+
+import (
+	"os"
+	"testing"
+)
+
+func TestValidAll(t *testing.T) {
+	tests := []struct {
+		in   string
+		want AllSubsets
+	}{
+		{"", AllSubsets{true, true, true}},
+		{"abc\t\r\n", AllSubsets{true, true, true}},
+		{"a\u0085b", AllSubsets{true, true, false}},
+		{"a\U0010FFFFb", AllSubsets{true, true, false}},
+		{"a\x00b", AllSubsets{true, false, false}},
+		{"a\uFFFEb\x00", AllSubsets{true, false, false}},
+		{"a\xffb", AllSubsets{false, false, false}},
+		{"\u0085\x00\xed\xba\xad", AllSubsets{false, false, false}},
+	}
+	for _, test := range tests {
+		if got := ValidAllString(test.in); got != test.want {
+			t.Errorf("ValidAllString(%q) = %+v, want %+v", test.in, got, test.want)
+		}
+		if got := ValidAllUtf8([]byte(test.in)); got != test.want {
+			t.Errorf("ValidAllUtf8(%q) = %+v, want %+v", test.in, got, test.want)
+		}
+	}
+}
+
+func TestValidAllStringAllocs(t *testing.T) {
+	s := "café au lait, with a long enough tail that copying it would show"
+	allocs := testing.AllocsPerRun(100, func() {
+		if ValidAllString(s) != (AllSubsets{true, true, true}) {
+			t.Fatal("ValidAllString rejected valid input")
+		}
+	})
+	if allocs != 0 {
+		t.Errorf("ValidAllString allocated %v times", allocs)
+	}
+}
+
+func TestValidAllSamples(t *testing.T) {
+	for _, name := range []string{"testdata/sample.txt", "testdata/UTF-8-test.txt"} {
+		file, err := os.ReadFile(name)
+		if err != nil {
+			t.Fatal(err)
+		}
+		want := AllSubsets{
+			Scalars:     Scalars.ValidUtf8(file),
+			XmlChars:    XmlChars.ValidUtf8(file),
+			Assignables: Assignables.ValidUtf8(file),
+		}
+		if got := ValidAllUtf8(file); got != want {
+			t.Errorf("ValidAllUtf8(%s) = %+v, want %+v", name, got, want)
+		}
+	}
+}
+
+func TestXmlButNotAssignable(t *testing.T) {
+	s := "a\u007f\x00b\u0085\xff\uFDD0c\uFFFE\U0002FFFF\U0001F600"
+	want := []Violation{
+		{Offset: 1, Index: 1, Width: 1, Rune: 0x7F, Problem: LegacyControl},
+		{Offset: 4, Index: 4, Width: 2, Rune: 0x85, Problem: LegacyControl},
+		{Offset: 7, Index: 6, Width: 3, Rune: 0xFDD0, Problem: Noncharacter},
+		{Offset: 14, Index: 9, Width: 4, Rune: 0x2FFFF, Problem: Noncharacter},
+	}
+	for i := range want {
+		want[i].Subset = Assignables
+	}
+	got := XmlButNotAssignable(s)
+	if len(got) != len(want) {
+		t.Fatalf("XmlButNotAssignable = %v, want %v", got, want)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Errorf("XmlButNotAssignable[%d] = %v, want %v", i, got[i], want[i])
+		}
+	}
+	if found := XmlButNotAssignable("plain text\n"); found != nil {
+		t.Errorf("XmlButNotAssignable found %v in plain text", found)
+	}
+}
+
+func TestCorpusSubsetStats(t *testing.T) {
+	items := []string{"", "plain", "nel\u0085", "nul\x00", "bad\xff", "\U0001F600"}
+	want := CorpusStats{Total: 6, Scalars: 5, XmlChars: 4, Assignables: 3}
+	if got := CorpusSubsetStats(items); got != want {
+		t.Errorf("CorpusSubsetStats = %+v, want %+v", got, want)
+	}
+	if got := CorpusSubsetStats(nil); got != (CorpusStats{}) {
+		t.Errorf("CorpusSubsetStats(nil) = %+v", got)
+	}
+}
@@ -0,0 +1,78 @@
+package rfc9839
+
+import (
+	"encoding/binary"
+	"unicode/utf8"
+)
+
+// ValidAscii checks u by looking only at its ASCII bytes, which is much cheaper than
+// decoding UTF-8. If u is entirely ASCII, or contains an ASCII byte which is not in the
+// subset, then ok is true and valid is the verdict for the whole of u. Otherwise ok is
+// false and the caller should fall back to ValidUtf8.
+func (sub *Subset) ValidAscii(u []byte) (valid, ok bool) {
+	ascii := true
+	for _, b := range u {
+		if b >= utf8.RuneSelf {
+			ascii = false
+		} else if !subsetContains(sub, rune(b)) {
+			return false, true
+		}
+	}
+	return ascii, ascii
+}
+
+// NoProblematicBytes is a cheap pre-filter which looks for the encodings of legacy controls
+// and noncharacters without decoding UTF-8. If it returns true and utf8.Valid(u) is also
+// true, then u is valid for Scalars, XmlChars, and Assignables, and the full check can be
+// skipped. If it returns false, u may still be valid, for example XmlChars permits DEL, and
+// the caller should fall back to ValidUtf8. Tab, newline, and carriage return are not
+// problematic.
+func NoProblematicBytes(u []byte) bool {
+	for i, b := range u {
+		switch {
+		case b < 0x20:
+			if b != '\t' && b != '\n' && b != '\r' {
+				return false
+			}
+		case b == 0x7F:
+			return false
+		case b == 0xC2:
+			// C1 controls, U+0080 through U+009F
+			if i+1 < len(u) && u[i+1] >= 0x80 && u[i+1] <= 0x9F {
+				return false
+			}
+		case b == 0xEF:
+			// U+FDD0 through U+FDEF, and U+FFFE and U+FFFF
+			if i+2 < len(u) && u[i+1] == 0xB7 && u[i+2] >= 0x90 && u[i+2] <= 0xAF {
+				return false
+			}
+			if i+2 < len(u) && u[i+1] == 0xBF && u[i+2] >= 0xBE {
+				return false
+			}
+		case b >= 0xF0:
+			// the last two code points of the astral planes, U+xFFFE and U+xFFFF
+			if i+3 < len(u) && u[i+1]&0x0F == 0x0F && u[i+2] == 0xBF && u[i+3] >= 0xBE {
+				return false
+			}
+		}
+	}
+	return true
+}
+
+const (
+	ones  = 0x0101010101010101
+	highs = 0x8080808080808080
+)
+
+// printableAscii8 reports whether the first 8 bytes of u, which must have at least 8, are all
+// printable ASCII, U+0020 through U+007E, by testing them together as a uint64.
+func printableAscii8(u []byte) bool {
+	w := binary.LittleEndian.Uint64(u)
+	nonAscii := w & highs
+	// a byte below 0x20 borrows into its high bit when 0x20 is subtracted from it
+	control := (w - 0x20*ones) &^ w & highs
+	// a byte of 0x7F becomes zero when XORed with 0x7F, and zero bytes borrow likewise
+	del := w ^ 0x7F*ones
+	del = (del - ones) &^ del & highs
+	return nonAscii|control|del == 0
+}
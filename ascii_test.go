@@ -0,0 +1,103 @@
+package rfc9839
+
+import (
+	"os"
+	"testing"
+	"unicode/utf8"
+)
+
+func TestValidAscii(t *testing.T) {
+	tests := []struct {
+		sub   *Subset
+		in    string
+		valid bool
+		ok    bool
+	}{
+		{XmlChars, "", true, true},
+		{XmlChars, "{\"a\": 1}\r\n", true, true},
+		{XmlChars, "a\x00b", false, true},
+		{XmlChars, "a\x7fb", true, true},
+		{Assignables, "a\x7fb", false, true},
+		{Scalars, "a\x00\x7fb", true, true},
+		{XmlChars, "café", false, false},
+		{XmlChars, "café\x01", false, true},
+		{XmlChars, "\xff", false, false},
+	}
+	for _, test := range tests {
+		valid, ok := test.sub.ValidAscii([]byte(test.in))
+		if valid != test.valid || ok != test.ok {
+			t.Errorf("ValidAscii(%q) = %t, %t, want %t, %t", test.in, valid, ok, test.valid, test.ok)
+		}
+		if ok && valid != test.sub.ValidUtf8([]byte(test.in)) {
+			t.Errorf("ValidAscii(%q) disagrees with ValidUtf8", test.in)
+		}
+	}
+
+	file, err := os.ReadFile("testdata/sample.txt")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if _, ok := XmlChars.ValidAscii(file); ok {
+		t.Error("sample text is not ASCII-only")
+	}
+}
+
+func TestNoProblematicBytes(t *testing.T) {
+	tests := []struct {
+		in   string
+		want bool
+	}{
+		{"", true},
+		{"{\"a\": 1}\t\r\n", true},
+		{"café 中 \U0001F600 \uFFFD \U0010FFFD", true},
+		{"a\x00b", false},
+		{"a\x7fb", false},
+		{"a\u0085b", false},
+		{"a\uFDD0b", false},
+		{"a\uFFFFb", false},
+		{"a\U0010FFFEb", false},
+	}
+	for _, test := range tests {
+		if got := NoProblematicBytes([]byte(test.in)); got != test.want {
+			t.Errorf("NoProblematicBytes(%q) = %t, want %t", test.in, got, test.want)
+		}
+	}
+
+	// the guarantee, and no false alarms for single scalars, over the whole code space
+	var buf [utf8.UTFMax]byte
+	for r := rune(0); r <= 0x10FFFF; r++ {
+		if !utf8.ValidRune(r) {
+			continue
+		}
+		u := buf[:utf8.EncodeRune(buf[:], r)]
+		got := NoProblematicBytes(u)
+		if got != Assignables.ValidRune(r) {
+			t.Fatalf("NoProblematicBytes(%X) = %t", r, got)
+		}
+		if got && !XmlChars.ValidRune(r) {
+			t.Fatalf("NoProblematicBytes(%X) = true, but it's not in XmlChars", r)
+		}
+	}
+}
+
+func TestPrintableAscii8(t *testing.T) {
+	// every byte value, in every position, among printable neighbours
+	for b := 0; b < 256; b++ {
+		want := b >= 0x20 && b <= 0x7E
+		for i := 0; i < 8; i++ {
+			u := []byte("abcdefgh")
+			u[i] = byte(b)
+			if got := printableAscii8(u); got != want {
+				t.Fatalf("printableAscii8(%q) = %t", u, got)
+			}
+		}
+	}
+	// the fast path must not change ValidUtf8's verdict
+	for _, in := range []string{"0123456789abcdef", "01234567\x7f", "0123456789ab\x00\xff", "01234567\u00e9"} {
+		for _, sub := range builtinSubsets {
+			if sub.ValidUtf8([]byte(in)) != sub.ValidString(in) {
+				t.Errorf("%s.ValidUtf8(%q) disagrees with ValidString", sub, in)
+			}
+		}
+	}
+}
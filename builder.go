@@ -0,0 +1,75 @@
+package rfc9839
+
+import (
+	"unicode"
+)
+
+// A SubsetBuilder makes a custom Subset by starting from another and allowing or denying
+// ranges of code points, for example Assignables without the private-use areas. Each of its
+// methods returns the builder, so that calls can be chained.
+type SubsetBuilder struct {
+	ranges []Range
+	name   string
+}
+
+// NewSubsetBuilder returns a SubsetBuilder which starts from the code points in base. Unless
+// Name is called, the built Subset is named after base, like "Assignables (modified)", so that
+// it's never mistaken for base itself in error messages.
+func NewSubsetBuilder(base *Subset) *SubsetBuilder {
+	b := &SubsetBuilder{ranges: base.SortedRanges()}
+	if base.name != "" {
+		b.name = base.name + " (modified)"
+	}
+	return b
+}
+
+// Name sets the name which the Subset's String method returns, for use in error messages.
+func (b *SubsetBuilder) Name(name string) *SubsetBuilder {
+	b.name = name
+	return b
+}
+
+// AllowRange adds the code points from lo to hi inclusive. Parts of the range outside 0
+// through unicode.MaxRune are ignored, and so are surrogates, which are not scalars and can't
+// be encoded in UTF-8, so that like the built-in subsets, no built subset contains them.
+func (b *SubsetBuilder) AllowRange(lo, hi rune) *SubsetBuilder {
+	lo, hi = max(lo, 0), min(hi, unicode.MaxRune)
+	if lo <= hi {
+		b.ranges = normalizeRanges(append(b.ranges, Range{lo, hi}))
+		b.DenyRange(0xD800, 0xDFFF)
+	}
+	return b
+}
+
+// DenyRange removes the code points from lo to hi inclusive.
+func (b *SubsetBuilder) DenyRange(lo, hi rune) *SubsetBuilder {
+	if lo > hi {
+		return b
+	}
+	var kept []Range
+	for _, r := range b.ranges {
+		if r.Hi < lo || r.Lo > hi {
+			kept = append(kept, r)
+			continue
+		}
+		if r.Lo < lo {
+			kept = append(kept, Range{r.Lo, lo - 1})
+		}
+		if r.Hi > hi {
+			kept = append(kept, Range{hi + 1, r.Hi})
+		}
+	}
+	b.ranges = kept
+	return b
+}
+
+// Build returns a new Subset with the builder's code points, which works with every method
+// that the built-in subsets do. Its Code is empty. The builder can go on being used.
+func (b *SubsetBuilder) Build() *Subset {
+	// numeric order suits every lookup layout; the binary searches need it
+	pairs := make([]runePair, len(b.ranges))
+	for i, r := range b.ranges {
+		pairs[i] = runePair{r.Lo, r.Hi}
+	}
+	return &Subset{pairs: pairs, name: b.name, ascii: containsPrintableAscii(pairs)}
+}
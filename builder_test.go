@@ -0,0 +1,65 @@
+package rfc9839
+
+import (
+	"slices"
+	"testing"
+)
+
+func TestSubsetBuilder(t *testing.T) {
+	noPrivate := NewSubsetBuilder(Assignables).
+		Name("AssignablesNoPrivateUse").
+		DenyRange(0xE000, 0xF8FF).
+		DenyRange(0xF0000, 0x10FFFF).
+		Build()
+	if noPrivate.String() != "AssignablesNoPrivateUse" || noPrivate.Code() != "" {
+		t.Errorf("built subset is named %q, code %q", noPrivate, noPrivate.Code())
+	}
+	unnamed := NewSubsetBuilder(Assignables).DenyRange(0xE000, 0xF8FF).Build()
+	if unnamed.String() != "Assignables (modified)" {
+		t.Errorf("unnamed built subset is named %q", unnamed)
+	}
+	for r := rune(0); r <= 0x10FFFF; r++ {
+		private := (r >= 0xE000 && r <= 0xF8FF) || r >= 0xF0000
+		if noPrivate.ValidRune(r) != (Assignables.ValidRune(r) && !private) {
+			t.Fatalf("ValidRune(%X) is wrong", r)
+		}
+	}
+	if !noPrivate.ValidString("hello, \U0001F600") || noPrivate.ValidUtf8([]byte("a\uE000")) {
+		t.Error("ValidString or ValidUtf8 is wrong")
+	}
+	v := noPrivate.FirstInvalidString("ab\U000F0001")
+	if v == nil || v.Offset != 2 || v.Subset != noPrivate {
+		t.Errorf("FirstInvalidString = %v", v)
+	}
+
+	tests := []struct {
+		b    *SubsetBuilder
+		want []Range
+	}{
+		{NewSubsetBuilder(Scalars).DenyRange(0x80, 0x10FFFF), []Range{{0, 0x7F}}},
+		{NewSubsetBuilder(Scalars).AllowRange(0xD800, 0xDFFF), []Range{{0, 0xD7FF}, {0xE000, 0x10FFFF}}},
+		{NewSubsetBuilder(Scalars).AllowRange(-5, 0x7FFFFFFF), []Range{{0, 0xD7FF}, {0xE000, 0x10FFFF}}},
+		{NewSubsetBuilder(Scalars).DenyRange(0, 0x10FFFF).AllowRange(0xD000, 0xDFFF), []Range{{0xD000, 0xD7FF}}},
+		{NewSubsetBuilder(Scalars).DenyRange(0, 0x10FFFF).AllowRange('a', 'z'), []Range{{'a', 'z'}}},
+		{NewSubsetBuilder(Scalars).DenyRange(0x30, 0x20), []Range{{0, 0xD7FF}, {0xE000, 0x10FFFF}}},
+		{NewSubsetBuilder(XmlChars).DenyRange(0xA, 0xD), []Range{{0x9, 0x9}, {0x20, 0xD7FF}, {0xE000, 0xFFFD}, {0x10000, 0x10FFFF}}},
+	}
+	for i, test := range tests {
+		if got := test.b.Build().SortedRanges(); !slices.Equal(got, test.want) {
+			t.Errorf("%d: built %v, want %v", i, got, test.want)
+		}
+	}
+
+	// code that decodes surrogates relies on no subset containing them
+	everything := NewSubsetBuilder(Scalars).AllowRange(0, 0x10FFFF).Build()
+	if everything.ValidRune(0xD800) || everything.CheckJsonText([]byte(`["\uD800"]`)) == nil ||
+		everything.FirstInvalidWtf8([]byte("\xed\xa0\x80")) == nil {
+		t.Error("built subset contains a surrogate")
+	}
+
+	// a subset without all of printable ASCII can't take the fast path in ValidUtf8
+	digits := NewSubsetBuilder(Scalars).DenyRange(0, 0x10FFFF).AllowRange('0', '9').Build()
+	if !digits.ValidUtf8([]byte("0123456789")) || digits.ValidUtf8([]byte("01234567a")) {
+		t.Error("digits subset is wrong")
+	}
+}
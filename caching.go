@@ -0,0 +1,78 @@
+package rfc9839
+
+import (
+	"unicode/utf8"
+)
+
+const (
+	notCached uint8 = iota
+	cachedValid
+	cachedInvalid
+)
+
+// A CachingValidator remembers the subset's verdict for each rune below a ceiling, so that
+// inputs drawn from a small alphabet are checked by indexing a table rather than by scanning
+// the subset's ranges. A CachingValidator is not safe for concurrent use.
+type CachingValidator struct {
+	sub   *Subset
+	cache []uint8
+}
+
+// NewCachingValidator returns a CachingValidator for sub which caches runes below ceiling;
+// 0x100 covers Latin-1.
+func NewCachingValidator(sub *Subset, ceiling rune) *CachingValidator {
+	if ceiling < 0 {
+		ceiling = 0
+	}
+	return &CachingValidator{sub: sub, cache: make([]uint8, ceiling)}
+}
+
+func (cv *CachingValidator) ValidRune(r rune) bool {
+	if r < 0 || int(r) >= len(cv.cache) {
+		return subsetContains(cv.sub, r)
+	}
+	switch cv.cache[r] {
+	case cachedValid:
+		return true
+	case cachedInvalid:
+		return false
+	}
+	valid := subsetContains(cv.sub, r)
+	if valid {
+		cv.cache[r] = cachedValid
+	} else {
+		cv.cache[r] = cachedInvalid
+	}
+	return valid
+}
+
+func (cv *CachingValidator) ValidString(s string) bool {
+	// decoded in place, since converting s to a []byte would copy it
+	index := 0
+	for index < len(s) {
+		r, width := utf8.DecodeRuneInString(s[index:])
+		if r == utf8.RuneError && width == 1 {
+			return false
+		}
+		if !cv.ValidRune(r) {
+			return false
+		}
+		index += width
+	}
+	return true
+}
+
+func (cv *CachingValidator) ValidUtf8(u []byte) bool {
+	index := 0
+	for index < len(u) {
+		r, width := utf8.DecodeRune(u[index:])
+		if r == utf8.RuneError && width == 1 {
+			return false
+		}
+		if !cv.ValidRune(r) {
+			return false
+		}
+		index += width
+	}
+	return true
+}
@@ -0,0 +1,59 @@
+package rfc9839
+
+import (
+	"os"
+	"testing"
+)
+
+func TestCachingValidatorMatchesSubset(t *testing.T) {
+	for _, sub := range []*Subset{Scalars, XmlChars, Assignables} {
+		for _, ceiling := range []rune{-1, 0, 0x100, 0x10000} {
+			cv := NewCachingValidator(sub, ceiling)
+			// twice, so that the second pass is served from the cache
+			for pass := 0; pass < 2; pass++ {
+				for r := rune(-1); r <= 0x10FFFF+1; r++ {
+					if cv.ValidRune(r) != sub.ValidRune(r) {
+						t.Fatalf("ceiling %x: CachingValidator.ValidRune(%x) disagrees with Subset", ceiling, r)
+					}
+				}
+			}
+		}
+	}
+}
+
+func TestCachingValidatorText(t *testing.T) {
+	for _, name := range []string{"testdata/sample.txt", "testdata/UTF-8-test.txt"} {
+		file, err := os.ReadFile(name)
+		if err != nil {
+			t.Fatal(err)
+		}
+		for _, sub := range []*Subset{Scalars, XmlChars, Assignables} {
+			cv := NewCachingValidator(sub, 0x100)
+			if cv.ValidUtf8(file) != sub.ValidUtf8(file) {
+				t.Errorf("CachingValidator.ValidUtf8(%s) disagrees with Subset", name)
+			}
+			if cv.ValidString(string(file)) != sub.ValidString(string(file)) {
+				t.Errorf("CachingValidator.ValidString(%s) disagrees with Subset", name)
+			}
+		}
+	}
+	cv := NewCachingValidator(Assignables, 0x100)
+	for _, s := range []string{"ACGTTGCA", "ACGT\x00", "ACGT\u0085", "ACGT\xff"} {
+		if cv.ValidString(s) != Assignables.ValidString(s) {
+			t.Errorf("CachingValidator.ValidString(%q) disagrees with Subset", s)
+		}
+	}
+}
+
+func TestCachingValidatorStringAllocs(t *testing.T) {
+	cv := NewCachingValidator(Assignables, 0x100)
+	s := "café au lait, with a long enough tail that copying it would show"
+	allocs := testing.AllocsPerRun(100, func() {
+		if !cv.ValidString(s) {
+			t.Fatal("ValidString rejected valid input")
+		}
+	})
+	if allocs != 0 {
+		t.Errorf("ValidString allocated %v times", allocs)
+	}
+}
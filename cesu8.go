@@ -0,0 +1,64 @@
+package rfc9839
+
+import (
+	"unicode/utf8"
+)
+
+// DecodeCesu8Rune is like utf8.DecodeRune, but for CESU-8, the encoding used by some Java
+// and database systems, in which a rune outside the BMP is encoded as its UTF-16 surrogate
+// pair, each half taking three bytes, so six in all. The four-byte sequences of UTF-8 are
+// invalid in CESU-8. A lone surrogate is decoded as itself, with a width of 3. Like
+// utf8.DecodeRune, it returns (utf8.RuneError, 1) for invalid encoding, and
+// (utf8.RuneError, 0) if u is empty.
+func DecodeCesu8Rune(u []byte) (r rune, width int) {
+	if len(u) > 0 && u[0] >= 0xF0 {
+		return utf8.RuneError, 1
+	}
+	hi, ok := decodeWtf8Surrogate(u)
+	if !ok {
+		return utf8.DecodeRune(u)
+	}
+	if lo, ok := decodeWtf8Surrogate(u[3:]); ok && hi <= 0xDBFF && lo >= 0xDC00 {
+		return 0x10000 + (hi-0xD800)<<10 + (lo - 0xDC00), 6
+	}
+	return hi, 3
+}
+
+// DecodeModifiedUtf8Rune is like DecodeCesu8Rune, but for Java's Modified UTF-8, which is
+// CESU-8 except that U+0000 is encoded as the two bytes C0 80, and a zero byte is invalid.
+func DecodeModifiedUtf8Rune(u []byte) (r rune, width int) {
+	if len(u) > 0 && u[0] == 0 {
+		return utf8.RuneError, 1
+	}
+	if len(u) >= 2 && u[0] == 0xC0 && u[1] == 0x80 {
+		return 0, 2
+	}
+	return DecodeCesu8Rune(u)
+}
+
+// FirstInvalidCesu8 is like FirstInvalidUtf8, but for CESU-8, as decoded by DecodeCesu8Rune.
+// The Violation's Offset and Width refer to the CESU-8 bytes, so a rune outside the BMP has a
+// Width of 6, and a lone surrogate is reported as itself, with a Problem of Surrogate.
+func (sub *Subset) FirstInvalidCesu8(u []byte) *Violation {
+	return sub.firstInvalidDecoded(u, DecodeCesu8Rune)
+}
+
+// FirstInvalidModifiedUtf8 is like FirstInvalidCesu8, but for Modified UTF-8, as decoded by
+// DecodeModifiedUtf8Rune.
+func (sub *Subset) FirstInvalidModifiedUtf8(u []byte) *Violation {
+	return sub.firstInvalidDecoded(u, DecodeModifiedUtf8Rune)
+}
+
+// firstInvalidDecoded is FirstInvalidUtf8 with decode in place of utf8.DecodeRune.
+func (sub *Subset) firstInvalidDecoded(u []byte, decode func([]byte) (rune, int)) *Violation {
+	offset := 0
+	for index := 0; offset < len(u); index++ {
+		r, width := decode(u[offset:])
+		if (r == utf8.RuneError && width == 1) || !subsetContains(sub, r) {
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		offset += width
+	}
+	return nil
+}
@@ -0,0 +1,71 @@
+package rfc9839
+
+import (
+	"testing"
+	"unicode/utf8"
+)
+
+func TestDecodeCesu8Rune(t *testing.T) {
+	tests := []struct {
+		in     string
+		r      rune
+		width  int
+		mr     rune // from DecodeModifiedUtf8Rune
+		mwidth int
+	}{
+		{"", utf8.RuneError, 0, utf8.RuneError, 0},
+		{"a", 'a', 1, 'a', 1},
+		{"é", 'é', 2, 'é', 2},
+		{"\uFFFD", 0xFFFD, 3, 0xFFFD, 3},
+		{"\xed\xa0\xbd\xed\xb8\x80", 0x1F600, 6, 0x1F600, 6},
+		{"\xed\xaf\xbf\xed\xbf\xbf", 0x10FFFF, 6, 0x10FFFF, 6},
+		{"\xed\xa0\xbdx", 0xD83D, 3, 0xD83D, 3},
+		{"\xed\xb8\x80\xed\xa0\xbd", 0xDE00, 3, 0xDE00, 3},
+		{"\U0001F600", utf8.RuneError, 1, utf8.RuneError, 1},
+		{"\xff", utf8.RuneError, 1, utf8.RuneError, 1},
+		{"\x00", 0, 1, utf8.RuneError, 1},
+		{"\xc0\x80", utf8.RuneError, 1, 0, 2},
+	}
+	for _, test := range tests {
+		u := []byte(test.in)
+		if r, width := DecodeCesu8Rune(u); r != test.r || width != test.width {
+			t.Errorf("DecodeCesu8Rune(%q) = %X, %d, want %X, %d", test.in, r, width, test.r, test.width)
+		}
+		if r, width := DecodeModifiedUtf8Rune(u); r != test.mr || width != test.mwidth {
+			t.Errorf("DecodeModifiedUtf8Rune(%q) = %X, %d, want %X, %d", test.in, r, width, test.mr, test.mwidth)
+		}
+	}
+}
+
+func TestFirstInvalidCesu8(t *testing.T) {
+	// U+1F600 and U+1FFFE, in CESU-8
+	smile, nonchar := "\xed\xa0\xbd\xed\xb8\x80", "\xed\xa0\xbf\xed\xbf\xbe"
+	tests := []struct {
+		in       string
+		want     *Violation
+		modified *Violation // from FirstInvalidModifiedUtf8, if it differs
+	}{
+		{"café " + smile, nil, nil},
+		{"a" + smile + nonchar, &Violation{Offset: 7, Index: 2, Width: 6, Rune: 0x1FFFE, Subset: Assignables, Problem: Noncharacter}, nil},
+		{"a\xed\xa0\xbd", &Violation{Offset: 1, Index: 1, Width: 3, Rune: 0xD83D, Subset: Assignables, Problem: Surrogate}, nil},
+		{"ab\U0001F600", &Violation{Offset: 2, Index: 2, Width: 1, Rune: utf8.RuneError, Subset: Assignables, Problem: InvalidUtf8}, nil},
+		{"a\xc0\x80", &Violation{Offset: 1, Index: 1, Width: 1, Rune: utf8.RuneError, Subset: Assignables, Problem: InvalidUtf8},
+			&Violation{Offset: 1, Index: 1, Width: 2, Rune: 0, Subset: Assignables, Problem: LegacyControl}},
+		{"a\x00", &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0, Subset: Assignables, Problem: LegacyControl},
+			&Violation{Offset: 1, Index: 1, Width: 1, Rune: utf8.RuneError, Subset: Assignables, Problem: InvalidUtf8}},
+	}
+	for _, test := range tests {
+		got := Assignables.FirstInvalidCesu8([]byte(test.in))
+		if (got == nil) != (test.want == nil) || (got != nil && *got != *test.want) {
+			t.Errorf("FirstInvalidCesu8(%q) = %v, want %v", test.in, got, test.want)
+		}
+		want := test.want
+		if test.modified != nil {
+			want = test.modified
+		}
+		got = Assignables.FirstInvalidModifiedUtf8([]byte(test.in))
+		if (got == nil) != (want == nil) || (got != nil && *got != *want) {
+			t.Errorf("FirstInvalidModifiedUtf8(%q) = %v, want %v", test.in, got, want)
+		}
+	}
+}
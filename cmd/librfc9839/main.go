@@ -0,0 +1,74 @@
+// Command librfc9839 exports the subset checks to C. Build it with
+//
+//	go build -buildmode=c-shared -o librfc9839.so ./cmd/librfc9839
+//
+// which also writes librfc9839.h, declaring:
+//
+//	int rfc9839_valid_utf8(int subset, char* p, size_t n);
+//	ptrdiff_t rfc9839_first_invalid_utf8(int subset, char* p, size_t n);
+//
+// The subset is one of the RFC9839_* constants below. rfc9839_valid_utf8 returns 1 if the n
+// bytes at p are valid for the subset and 0 if not, and rfc9839_first_invalid_utf8 returns
+// the byte offset of the first violation, or -1 if there is none. Both return -2 for an
+// unknown subset. Neither retains p.
+package main
+
+/*
+#include <stddef.h>
+
+enum {
+	RFC9839_SCALARS = 0,
+	RFC9839_XML_CHARS = 1,
+	RFC9839_ASSIGNABLES = 2,
+};
+*/
+import "C"
+
+import (
+	"unsafe"
+
+	"github.com/timbray/rfc9839"
+)
+
+// the order of the RFC9839_* constants
+var subsets = []*rfc9839.Subset{rfc9839.Scalars, rfc9839.XmlChars, rfc9839.Assignables}
+
+//export rfc9839_valid_utf8
+func rfc9839_valid_utf8(subset C.int, p *C.char, n C.size_t) C.int {
+	return C.int(validUtf8(int(subset), bytesAt(p, n)))
+}
+
+//export rfc9839_first_invalid_utf8
+func rfc9839_first_invalid_utf8(subset C.int, p *C.char, n C.size_t) C.ptrdiff_t {
+	return C.ptrdiff_t(firstInvalidUtf8(int(subset), bytesAt(p, n)))
+}
+
+// bytesAt views C memory as a slice, without copying it.
+func bytesAt(p *C.char, n C.size_t) []byte {
+	if n == 0 {
+		return nil
+	}
+	return unsafe.Slice((*byte)(unsafe.Pointer(p)), int(n))
+}
+
+func validUtf8(subset int, u []byte) int {
+	if subset < 0 || subset >= len(subsets) {
+		return -2
+	}
+	if subsets[subset].ValidUtf8(u) {
+		return 1
+	}
+	return 0
+}
+
+func firstInvalidUtf8(subset int, u []byte) int {
+	if subset < 0 || subset >= len(subsets) {
+		return -2
+	}
+	if v := subsets[subset].FirstInvalidUtf8(u); v != nil {
+		return v.Offset
+	}
+	return -1
+}
+
+func main() {}
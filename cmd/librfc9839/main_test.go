@@ -0,0 +1,31 @@
+package main
+
+import (
+	"testing"
+)
+
+func TestExports(t *testing.T) {
+	tests := []struct {
+		subset int
+		in     string
+		valid  int
+		first  int
+	}{
+		{0, "", 1, -1},
+		{0, "a\x00\x7f", 1, -1},
+		{1, "a\x00", 0, 1},
+		{1, "a\x7f", 1, -1},
+		{2, "ab\x7f", 0, 2},
+		{2, "\xff", 0, 0},
+		{3, "a", -2, -2},
+		{-1, "a", -2, -2},
+	}
+	for _, test := range tests {
+		if got := validUtf8(test.subset, []byte(test.in)); got != test.valid {
+			t.Errorf("validUtf8(%d, %q) = %d, want %d", test.subset, test.in, got, test.valid)
+		}
+		if got := firstInvalidUtf8(test.subset, []byte(test.in)); got != test.first {
+			t.Errorf("firstInvalidUtf8(%d, %q) = %d, want %d", test.subset, test.in, got, test.first)
+		}
+	}
+}
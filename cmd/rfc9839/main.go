@@ -0,0 +1,103 @@
+// Command rfc9839 checks files, or its standard input, for code points which are not in one
+// of the RFC 9839 subsets. It prints each violation as file:line:column, with the column
+// counted in runes, and exits with status 1 if there were any, or 2 for other errors.
+//
+// Usage:
+//
+//	rfc9839 [-subset scalars|xml|assignable] [file ...]
+package main
+
+import (
+	"bufio"
+	"flag"
+	"fmt"
+	"io"
+	"os"
+	"unicode/utf8"
+
+	"github.com/timbray/rfc9839"
+)
+
+var subsets = map[string]*rfc9839.Subset{
+	"scalars":    rfc9839.Scalars,
+	"xml":        rfc9839.XmlChars,
+	"assignable": rfc9839.Assignables,
+}
+
+func main() {
+	os.Exit(run(os.Args[1:], os.Stdin, os.Stdout, os.Stderr))
+}
+
+func run(args []string, stdin io.Reader, stdout, stderr io.Writer) int {
+	flags := flag.NewFlagSet("rfc9839", flag.ContinueOnError)
+	flags.SetOutput(stderr)
+	subsetName := flags.String("subset", "assignable", "the subset to check against: scalars, xml, or assignable")
+	if err := flags.Parse(args); err != nil {
+		return 2
+	}
+	sub, ok := subsets[*subsetName]
+	if !ok {
+		fmt.Fprintf(stderr, "rfc9839: unknown subset %q\n", *subsetName)
+		return 2
+	}
+
+	status := 0
+	check := func(name string, r io.Reader) {
+		found, err := report(stdout, name, r, sub)
+		switch {
+		case err != nil:
+			fmt.Fprintf(stderr, "rfc9839: %s: %v\n", name, err)
+			status = 2
+		case found:
+			status = max(status, 1)
+		}
+	}
+	if flags.NArg() == 0 {
+		check("<stdin>", stdin)
+		return status
+	}
+	for _, name := range flags.Args() {
+		f, err := os.Open(name)
+		if err != nil {
+			fmt.Fprintf(stderr, "rfc9839: %v\n", err)
+			status = 2
+			continue
+		}
+		check(name, f)
+		f.Close()
+	}
+	return status
+}
+
+// report prints each violation in the input from r and reports whether there were any. It
+// streams the input, counting lines and columns as it goes, so that neither a large input
+// nor a long line with many violations costs more than a single pass.
+func report(w io.Writer, name string, r io.Reader, sub *rfc9839.Subset) (bool, error) {
+	br := bufio.NewReaderSize(r, 64*1024)
+	found := false
+	line, column := 1, 1
+	for {
+		c, size, err := br.ReadRune()
+		if err == io.EOF {
+			return found, nil
+		}
+		if err != nil {
+			return found, err
+		}
+		switch {
+		case c == utf8.RuneError && size == 1:
+			// step back to recover the offending byte
+			_ = br.UnreadRune()
+			b, _ := br.ReadByte()
+			fmt.Fprintf(w, "%s:%d:%d: invalid UTF-8 byte 0x%02X\n", name, line, column, b)
+			found = true
+		case !sub.ValidRune(c):
+			fmt.Fprintf(w, "%s:%d:%d: U+%04X (%s)\n", name, line, column, c, rfc9839.Classify(c))
+			found = true
+		}
+		column++
+		if c == '\n' {
+			line, column = line+1, 1
+		}
+	}
+}
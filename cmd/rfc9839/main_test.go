@@ -0,0 +1,73 @@
+package main
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestRun(t *testing.T) {
+	tests := []struct {
+		args   []string
+		stdin  string
+		status int
+		stdout string
+	}{
+		{nil, "all good\n", 0, ""},
+		{nil, "line one\nté\x01\n\xff\n", 1, "<stdin>:2:3: U+0001 (legacy control)\n<stdin>:3:1: invalid UTF-8 byte 0xFF\n"},
+		{[]string{"-subset", "xml"}, "\x7f\uFFFE", 1, "<stdin>:1:2: U+FFFE (noncharacter)\n"},
+		{[]string{"-subset", "scalars"}, "\x00\x7f\uFFFE", 0, ""},
+		{[]string{"-subset", "latin1"}, "", 2, ""},
+		{[]string{"-no-such-flag"}, "", 2, ""},
+	}
+	for _, test := range tests {
+		var stdout, stderr bytes.Buffer
+		status := run(test.args, strings.NewReader(test.stdin), &stdout, &stderr)
+		if status != test.status || stdout.String() != test.stdout {
+			t.Errorf("run(%q) = %d, %q, want %d, %q", test.args, status, stdout.String(), test.status, test.stdout)
+		}
+		if (status == 2) != (stderr.Len() > 0) {
+			t.Errorf("run(%q) stderr = %q", test.args, stderr.String())
+		}
+	}
+}
+
+func TestRunFiles(t *testing.T) {
+	dir := t.TempDir()
+	good := filepath.Join(dir, "good.txt")
+	bad := filepath.Join(dir, "bad.txt")
+	if err := os.WriteFile(good, []byte("fine\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(bad, []byte("ok\n\x00"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	var stdout, stderr bytes.Buffer
+	status := run([]string{good, bad}, nil, &stdout, &stderr)
+	if status != 1 || stdout.String() != bad+":2:1: U+0000 (legacy control)\n" {
+		t.Errorf("run = %d, %q", status, stdout.String())
+	}
+
+	stdout.Reset()
+	stderr.Reset()
+	status = run([]string{filepath.Join(dir, "missing.txt"), bad}, nil, &stdout, &stderr)
+	if status != 2 || stderr.Len() == 0 || !strings.Contains(stdout.String(), "U+0000") {
+		t.Errorf("run with a missing file = %d, %q, %q", status, stdout.String(), stderr.String())
+	}
+}
+
+func TestRunLongLine(t *testing.T) {
+	// one line full of violations, which once took time quadratic in its length
+	in := strings.Repeat("é\x01", 100000)
+	var stdout, stderr bytes.Buffer
+	if status := run(nil, strings.NewReader(in), &stdout, &stderr); status != 1 {
+		t.Fatalf("run = %d, %q", status, stderr.String())
+	}
+	lines := strings.Split(strings.TrimSuffix(stdout.String(), "\n"), "\n")
+	if len(lines) != 100000 || lines[0] != "<stdin>:1:2: U+0001 (legacy control)" ||
+		lines[len(lines)-1] != "<stdin>:1:200000: U+0001 (legacy control)" {
+		t.Errorf("run reported %d violations, the last %q", len(lines), lines[len(lines)-1])
+	}
+}
@@ -0,0 +1,64 @@
+//go:build js && wasm
+
+// Command rfc9839wasm makes the subset checks available to JavaScript, so that a browser can
+// run the same tables as a Go backend. Build it with
+//
+//	GOOS=js GOARCH=wasm go build -o rfc9839.wasm ./cmd/rfc9839wasm
+//
+// and load it with the wasm_exec.js which ships with Go. It sets globalThis.rfc9839 to an
+// object with these methods, each taking a subset Code such as "XML" and a string:
+//
+//	validString(code, s)  // true or false
+//	firstInvalid(code, s) // index of the first violation in UTF-16 code units, or -1
+//	sanitize(code, s)     // s with each violation replaced by U+FFFD
+//
+// Each returns null for an unknown code. Strings are converted to UTF-8 on the way in, which
+// replaces unpaired surrogates with U+FFFD, so those are not reported.
+package main
+
+import (
+	"syscall/js"
+	"unicode/utf16"
+
+	"github.com/timbray/rfc9839"
+)
+
+// export wraps f, which takes a subset and a string, as a JavaScript function.
+func export(f func(sub *rfc9839.Subset, s string) any) js.Func {
+	return js.FuncOf(func(_ js.Value, args []js.Value) any {
+		if len(args) < 2 {
+			return nil
+		}
+		sub := rfc9839.SubsetFromCode(args[0].String())
+		if sub == nil {
+			return nil
+		}
+		return f(sub, args[1].String())
+	})
+}
+
+func validString(sub *rfc9839.Subset, s string) any {
+	return sub.ValidString(s)
+}
+
+func firstInvalid(sub *rfc9839.Subset, s string) any {
+	v := sub.FirstInvalidString(s)
+	if v == nil {
+		return -1
+	}
+	return len(utf16.Encode([]rune(s[:v.Offset])))
+}
+
+func sanitize(sub *rfc9839.Subset, s string) any {
+	return sub.SanitizeString(s)
+}
+
+func main() {
+	js.Global().Set("rfc9839", js.ValueOf(map[string]any{
+		"validString":  export(validString),
+		"firstInvalid": export(firstInvalid),
+		"sanitize":     export(sanitize),
+	}))
+	// the exports must outlive main
+	select {}
+}
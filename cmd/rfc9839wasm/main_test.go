@@ -0,0 +1,25 @@
+//go:build js && wasm
+
+package main
+
+import (
+	"testing"
+
+	"github.com/timbray/rfc9839"
+)
+
+func TestExports(t *testing.T) {
+	if validString(rfc9839.XmlChars, "ok\x00") != false || validString(rfc9839.XmlChars, "ok") != true {
+		t.Error("validString is wrong")
+	}
+	// the index counts UTF-16 code units, as JavaScript does
+	if got := firstInvalid(rfc9839.XmlChars, "\U0001F600é\x00"); got != 3 {
+		t.Errorf("firstInvalid = %v, want 3", got)
+	}
+	if got := firstInvalid(rfc9839.XmlChars, "fine"); got != -1 {
+		t.Errorf("firstInvalid = %v, want -1", got)
+	}
+	if got := sanitize(rfc9839.Assignables, "a\x7fb"); got != "a\uFFFDb" {
+		t.Errorf("sanitize = %q", got)
+	}
+}
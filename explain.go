@@ -0,0 +1,75 @@
+package rfc9839
+
+import (
+	"unicode"
+)
+
+// An AssignableReason says which of the gaps in Assignables a code point falls into.
+type AssignableReason int
+
+const (
+	AssignableOk                AssignableReason = iota // in Assignables
+	AssignableC0                                        // a C0 control other than tab, newline, and carriage return
+	AssignableC1OrDel                                   // DEL or a C1 control, U+007F through U+009F
+	AssignableSurrogate                                 // U+D800 through U+DFFF
+	AssignableNoncharacterBlock                         // U+FDD0 through U+FDEF
+	AssignablePlaneNoncharacter                         // the last two code points of a plane, U+xFFFE and U+xFFFF
+	AssignableOutOfRange                                // a negative rune, or one greater than unicode.MaxRune
+)
+
+var assignableReasonNames = []string{
+	AssignableOk:                "assignable",
+	AssignableC0:                "C0 control",
+	AssignableC1OrDel:           "DEL or C1 control",
+	AssignableSurrogate:         "surrogate",
+	AssignableNoncharacterBlock: "noncharacter block",
+	AssignablePlaneNoncharacter: "plane-final noncharacter",
+	AssignableOutOfRange:        "out of range",
+}
+
+func (reason AssignableReason) String() string {
+	if reason < 0 || int(reason) >= len(assignableReasonNames) {
+		return "unknown reason"
+	}
+	return assignableReasonNames[reason]
+}
+
+// An AssignableExplanation is the verdict of ExplainAssignable. Lo and Hi are the inclusive
+// bounds of the range the code point fell into: one of the ranges of Assignables if Reason is
+// AssignableOk, otherwise the gap between them which is responsible. For AssignableOutOfRange,
+// both are the rune itself.
+type AssignableExplanation struct {
+	Reason AssignableReason
+	Lo     rune
+	Hi     rune
+}
+
+// ExplainAssignable reports whether r is in Assignables and, if it isn't, why not.
+func ExplainAssignable(r rune) AssignableExplanation {
+	switch {
+	case r < 0 || r > unicode.MaxRune:
+		return AssignableExplanation{AssignableOutOfRange, r, r}
+	case r <= 0x8:
+		return AssignableExplanation{AssignableC0, 0, 0x8}
+	case r == 0xB || r == 0xC:
+		return AssignableExplanation{AssignableC0, 0xB, 0xC}
+	case r >= 0xE && r <= 0x1F:
+		return AssignableExplanation{AssignableC0, 0xE, 0x1F}
+	case r >= 0x7F && r <= 0x9F:
+		return AssignableExplanation{AssignableC1OrDel, 0x7F, 0x9F}
+	case r >= 0xD800 && r <= 0xDFFF:
+		return AssignableExplanation{AssignableSurrogate, 0xD800, 0xDFFF}
+	case r >= 0xFDD0 && r <= 0xFDEF:
+		return AssignableExplanation{AssignableNoncharacterBlock, 0xFDD0, 0xFDEF}
+	case r&0xFFFE == 0xFFFE:
+		lo := r &^ 1
+		return AssignableExplanation{AssignablePlaneNoncharacter, lo, lo + 1}
+	}
+	for _, pair := range Assignables.pairs {
+		if r >= pair.lo && r <= pair.hi {
+			return AssignableExplanation{AssignableOk, pair.lo, pair.hi}
+		}
+	}
+	// not reached: the cases above cover every gap in Assignables
+	return AssignableExplanation{AssignableOutOfRange, r, r}
+}
@@ -0,0 +1,44 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestExplainAssignable(t *testing.T) {
+	tests := []struct {
+		r    rune
+		want AssignableExplanation
+	}{
+		{'a', AssignableExplanation{AssignableOk, 0x20, 0x7E}},
+		{'\n', AssignableExplanation{AssignableOk, 0xA, 0xA}},
+		{0x10FFFD, AssignableExplanation{AssignableOk, 0x100000, 0x10FFFD}},
+		{0, AssignableExplanation{AssignableC0, 0, 0x8}},
+		{0xC, AssignableExplanation{AssignableC0, 0xB, 0xC}},
+		{0x1B, AssignableExplanation{AssignableC0, 0xE, 0x1F}},
+		{0x7F, AssignableExplanation{AssignableC1OrDel, 0x7F, 0x9F}},
+		{0x85, AssignableExplanation{AssignableC1OrDel, 0x7F, 0x9F}},
+		{0xDC00, AssignableExplanation{AssignableSurrogate, 0xD800, 0xDFFF}},
+		{0xFDD5, AssignableExplanation{AssignableNoncharacterBlock, 0xFDD0, 0xFDEF}},
+		{0xFFFF, AssignableExplanation{AssignablePlaneNoncharacter, 0xFFFE, 0xFFFF}},
+		{0x5FFFE, AssignableExplanation{AssignablePlaneNoncharacter, 0x5FFFE, 0x5FFFF}},
+		{0x110000, AssignableExplanation{AssignableOutOfRange, 0x110000, 0x110000}},
+		{-1, AssignableExplanation{AssignableOutOfRange, -1, -1}},
+	}
+	for _, test := range tests {
+		got := ExplainAssignable(test.r)
+		if got != test.want {
+			t.Errorf("ExplainAssignable(%X) = %v, want %v", test.r, got, test.want)
+		}
+	}
+
+	// the verdict must agree with the table, and the range must contain the rune
+	for r := rune(0); r <= 0x10FFFF; r++ {
+		got := ExplainAssignable(r)
+		if (got.Reason == AssignableOk) != Assignables.ValidRune(r) {
+			t.Fatalf("ExplainAssignable(%X) = %v, but ValidRune is %t", r, got, Assignables.ValidRune(r))
+		}
+		if r < got.Lo || r > got.Hi {
+			t.Fatalf("ExplainAssignable(%X) = %v, range doesn't contain it", r, got)
+		}
+	}
+}
@@ -0,0 +1,42 @@
+package rfc9839
+
+import (
+	"io"
+	"os"
+)
+
+// checkBufferSize is the size of the buffer CheckReader reads into.
+const checkBufferSize = 64 * 1024
+
+// CheckReader reads r to the end, checking it against the subset in fixed-size pieces so
+// that the whole input is never held in memory. It returns nil, a *Violation with its Offset
+// counted from the start of the input, or the error from r. It stops reading at the first
+// Violation.
+func (sub *Subset) CheckReader(r io.Reader) error {
+	sv := NewStreamValidator(sub)
+	buf := make([]byte, checkBufferSize)
+	for {
+		n, err := r.Read(buf)
+		if verr := sv.Feed(buf[:n]); verr != nil {
+			return verr
+		}
+		if err == io.EOF {
+			return sv.Finish()
+		}
+		if err != nil {
+			return err
+		}
+	}
+}
+
+// CheckFile is CheckReader applied to the named file. Go's standard library has no portable
+// memory mapping, so the file is streamed through a small buffer instead, which costs just
+// as little memory however large the file is.
+func (sub *Subset) CheckFile(path string) error {
+	f, err := os.Open(path)
+	if err != nil {
+		return err
+	}
+	defer f.Close()
+	return sub.CheckReader(f)
+}
@@ -0,0 +1,45 @@
+package rfc9839
+
+import (
+	"errors"
+	"io/fs"
+	"os"
+	"strings"
+	"testing"
+	"testing/iotest"
+)
+
+func TestCheckFile(t *testing.T) {
+	for _, name := range []string{"testdata/sample.txt", "testdata/UTF-8-test.txt"} {
+		file, err := os.ReadFile(name)
+		if err != nil {
+			t.Fatal(err)
+		}
+		want := Assignables.FirstInvalidUtf8(file)
+		err = Assignables.CheckFile(name)
+		var got *Violation
+		if errors.As(err, &got) != (want != nil) || (want != nil && *got != *want) {
+			t.Errorf("CheckFile(%s) = %v, want %v", name, err, want)
+		}
+	}
+	if err := Assignables.CheckFile("testdata/no-such-file"); !errors.Is(err, fs.ErrNotExist) {
+		t.Errorf("CheckFile of a missing file = %v", err)
+	}
+}
+
+func TestCheckReader(t *testing.T) {
+	in := strings.Repeat("café \U0001F600 ", checkBufferSize/4) + "\x00"
+	want := Assignables.FirstInvalidString(in)
+	// one byte at a time, so that runes are split between reads
+	err := Assignables.CheckReader(iotest.OneByteReader(strings.NewReader(in)))
+	var got *Violation
+	if !errors.As(err, &got) || *got != *want {
+		t.Errorf("CheckReader = %v, want %v", err, want)
+	}
+	if err := Assignables.CheckReader(strings.NewReader(in[:len(in)-1])); err != nil {
+		t.Errorf("CheckReader of clean input = %v", err)
+	}
+	if err := Assignables.CheckReader(iotest.ErrReader(iotest.ErrTimeout)); err != iotest.ErrTimeout {
+		t.Errorf("CheckReader of a failing reader = %v", err)
+	}
+}
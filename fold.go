@@ -0,0 +1,38 @@
+package rfc9839
+
+import (
+	"strings"
+	"unicode"
+)
+
+// ToLower returns s with all its runes mapped to lower case, as strings.ToLower does, if
+// every rune in s is in the subset; otherwise it returns a *Violation describing the first
+// one that isn't. A rune whose lower case is not in the subset, which can only happen with
+// a subset from SubsetBuilder, is left as it is, so the result is always in the subset.
+func (sub *Subset) ToLower(s string) (string, error) {
+	return sub.mapChecked(s, unicode.ToLower)
+}
+
+// ToUpper is like ToLower, but maps to upper case.
+func (sub *Subset) ToUpper(s string) (string, error) {
+	return sub.mapChecked(s, unicode.ToUpper)
+}
+
+func (sub *Subset) mapChecked(s string, mapping func(rune) rune) (string, error) {
+	var b strings.Builder
+	b.Grow(len(s))
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			return "", &v
+		}
+		if mapped := mapping(r); subsetContains(sub, mapped) {
+			r = mapped
+		}
+		b.WriteRune(r)
+		offset += width
+	}
+	return b.String(), nil
+}
@@ -0,0 +1,56 @@
+package rfc9839
+
+import (
+	"errors"
+	"os"
+	"strings"
+	"testing"
+)
+
+func TestToLowerAndUpper(t *testing.T) {
+	for _, s := range []string{"", "Hello, World", "ÉCOLE Straße ΣΊΣΥΦΟΣ", "Ǆ\U0001E900"} {
+		lower, err := XmlChars.ToLower(s)
+		if err != nil || lower != strings.ToLower(s) {
+			t.Errorf("ToLower(%q) = %q, %v, want %q", s, lower, err, strings.ToLower(s))
+		}
+		upper, err := XmlChars.ToUpper(s)
+		if err != nil || upper != strings.ToUpper(s) {
+			t.Errorf("ToUpper(%q) = %q, %v, want %q", s, upper, err, strings.ToUpper(s))
+		}
+	}
+
+	_, err := Assignables.ToLower("KEY\u0085")
+	var v *Violation
+	if !errors.As(err, &v) || v.Offset != 3 || v.Rune != 0x85 {
+		t.Errorf("ToLower should fail at offset 3, got %v", err)
+	}
+	_, err = XmlChars.ToUpper("key\xff")
+	if !errors.As(err, &v) || v.Offset != 3 || !v.BadUtf8() {
+		t.Errorf("ToUpper should fail at offset 3, got %v", err)
+	}
+
+	// a custom subset may lack a rune's other case, which then stays as it was
+	noUpperA := NewSubsetBuilder(Assignables).DenyRange('A', 'A').Build()
+	if upper, err := noUpperA.ToUpper("abc"); err != nil || upper != "aBC" {
+		t.Errorf("ToUpper without A = %q, %v", upper, err)
+	}
+	noLowerZ := NewSubsetBuilder(Assignables).DenyRange('z', 'z').Build()
+	if lower, err := noLowerZ.ToLower("XYZ"); err != nil || lower != "xyZ" {
+		t.Errorf("ToLower without z = %q, %v", lower, err)
+	}
+
+	file, err := os.ReadFile("testdata/sample.txt")
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, sub := range []*Subset{Scalars, XmlChars, Assignables} {
+		lower, err := sub.ToLower(string(file))
+		if err != nil || !sub.ValidString(lower) {
+			t.Errorf("%s.ToLower of sample: %v", sub, err)
+		}
+		upper, err := sub.ToUpper(string(file))
+		if err != nil || !sub.ValidString(upper) {
+			t.Errorf("%s.ToUpper of sample: %v", sub, err)
+		}
+	}
+}
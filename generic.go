@@ -0,0 +1,50 @@
+package rfc9839
+
+import (
+	"reflect"
+)
+
+// Text is the constraint of the generic functions, which accept both strings and byte slices,
+// including named types such as json.RawMessage, without conversions at the call site.
+type Text interface {
+	~string | ~[]byte
+}
+
+// Valid reports whether text is valid for sub, by calling ValidString or ValidUtf8.
+func Valid[T Text](sub *Subset, text T) bool {
+	s, u, isString := splitText(text)
+	if isString {
+		return sub.ValidString(s)
+	}
+	return sub.ValidUtf8(u)
+}
+
+// FirstInvalid returns the first Violation in text, or nil if it is valid for sub, by
+// calling FirstInvalidString or FirstInvalidUtf8.
+func FirstInvalid[T Text](sub *Subset, text T) *Violation {
+	s, u, isString := splitText(text)
+	if isString {
+		return sub.FirstInvalidString(s)
+	}
+	return sub.FirstInvalidUtf8(u)
+}
+
+// Check returns nil if text is valid for sub, or a *Violation, by calling CheckString or
+// CheckUtf8.
+func Check[T Text](sub *Subset, text T) error {
+	s, u, isString := splitText(text)
+	if isString {
+		return sub.CheckString(s)
+	}
+	return sub.CheckUtf8(u)
+}
+
+// splitText returns text as a string or a byte slice, whichever it is, without copying it.
+// Generic code is compiled separately for string-shaped and slice-shaped types, so the
+// conversion taken here is a no-op, even for named types such as json.RawMessage.
+func splitText[T Text](text T) (s string, u []byte, isString bool) {
+	if reflect.TypeFor[T]().Kind() == reflect.String {
+		return string(text), nil, true
+	}
+	return "", []byte(text), false
+}
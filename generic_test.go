@@ -0,0 +1,54 @@
+package rfc9839
+
+import (
+	"encoding/json"
+	"errors"
+	"testing"
+)
+
+type label string
+
+func TestGeneric(t *testing.T) {
+	if !Valid(XmlChars, "fine") || !Valid(XmlChars, []byte("fine")) || !Valid(XmlChars, label("fine")) {
+		t.Error("Valid rejected valid text")
+	}
+	if Valid(XmlChars, "a\x00") || Valid(XmlChars, []byte("a\x00")) || Valid(XmlChars, json.RawMessage("\"a\x00\"")) {
+		t.Error("Valid accepted invalid text")
+	}
+
+	want := XmlChars.FirstInvalidString("ab\xff")
+	for _, got := range []*Violation{
+		FirstInvalid(XmlChars, "ab\xff"),
+		FirstInvalid(XmlChars, []byte("ab\xff")),
+		FirstInvalid(XmlChars, label("ab\xff")),
+		FirstInvalid(XmlChars, json.RawMessage("ab\xff")),
+	} {
+		if got == nil || *got != *want {
+			t.Errorf("FirstInvalid = %v, want %v", got, want)
+		}
+	}
+	if v := FirstInvalid(XmlChars, label("ok")); v != nil {
+		t.Errorf("FirstInvalid(ok) = %v", v)
+	}
+
+	var v *Violation
+	if err := Check(Assignables, label("\x7f")); !errors.As(err, &v) || v.Rune != 0x7F {
+		t.Errorf("Check = %v", err)
+	}
+	if err := Check(Assignables, []byte(nil)); err != nil {
+		t.Errorf("Check(nil) = %v", err)
+	}
+}
+
+func TestGenericAllocs(t *testing.T) {
+	s := label("a label long enough that copying it would show")
+	u := json.RawMessage(`{"key": "a value long enough that copying it would show"}`)
+	allocs := testing.AllocsPerRun(100, func() {
+		if !Valid(Assignables, s) || !Valid(Assignables, u) || Check(Assignables, u) != nil {
+			t.Fatal("rejected valid input")
+		}
+	})
+	if allocs != 0 {
+		t.Errorf("Valid and Check allocated %v times", allocs)
+	}
+}
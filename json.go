@@ -0,0 +1,168 @@
+package rfc9839
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+	"unicode/utf8"
+)
+
+// A JsonViolation reports a string value or object member name, somewhere in a JSON value,
+// which is not valid for a Subset.
+type JsonViolation struct {
+	Pointer string // RFC 6901 JSON Pointer to the offending string, or to the member whose name is offending
+	Key     bool   // whether the violation is in a member name rather than a string value
+	Violation
+}
+
+func (jv *JsonViolation) Error() string {
+	where := "value"
+	if jv.Key {
+		where = "member name"
+	}
+	return fmt.Sprintf("%s, in the %s at %q", jv.Violation.Error(), where, jv.Pointer)
+}
+
+// Unwrap returns the underlying Violation, whose offsets are relative to the offending string.
+func (jv *JsonViolation) Unwrap() error {
+	return &jv.Violation
+}
+
+var pointerEscaper = strings.NewReplacer("~", "~0", "/", "~1")
+
+// CheckJsonValue walks v, as produced by unmarshaling JSON into an interface value, and
+// checks every string value and object member name against the subset. Members are visited
+// in sorted order, so the result is deterministic. It returns nil or a *JsonViolation.
+//
+// encoding/json replaces invalid UTF-8 and escaped surrogates with U+FFFD as it decodes, so
+// those problems are invisible by the time v is built.
+func (sub *Subset) CheckJsonValue(v any) error {
+	return sub.checkJsonValue(v, "")
+}
+
+func (sub *Subset) checkJsonValue(v any, pointer string) error {
+	switch v := v.(type) {
+	case string:
+		if bad := sub.FirstInvalidString(v); bad != nil {
+			return &JsonViolation{Pointer: pointer, Violation: *bad}
+		}
+	case []any:
+		for i, element := range v {
+			if err := sub.checkJsonValue(element, pointer+"/"+strconv.Itoa(i)); err != nil {
+				return err
+			}
+		}
+	case map[string]any:
+		names := make([]string, 0, len(v))
+		for name := range v {
+			names = append(names, name)
+		}
+		sort.Strings(names)
+		for _, name := range names {
+			member := pointer + "/" + pointerEscaper.Replace(name)
+			if bad := sub.FirstInvalidString(name); bad != nil {
+				return &JsonViolation{Pointer: member, Key: true, Violation: *bad}
+			}
+			if err := sub.checkJsonValue(v[name], member); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
+
+// ErrJsonSyntax is wrapped by the error CheckJsonText returns for a string it can't decode.
+var ErrJsonSyntax = errors.New("rfc9839: malformed JSON string")
+
+// CheckJsonText scans the raw JSON in text and checks the contents of every string, including
+// member names, against the subset after decoding escapes, so that it catches what
+// encoding/json hides: escaped surrogates, whether lone or not, escaped controls, and
+// invalid UTF-8. It returns nil, a *Violation whose Offset and Width identify the offending
+// bytes or escape in text, or an error wrapping ErrJsonSyntax. It checks only the strings,
+// not that text is otherwise well-formed JSON.
+func (sub *Subset) CheckJsonText(text []byte) error {
+	inString := false
+	offset := 0
+	for offset < len(text) {
+		if !inString {
+			inString = text[offset] == '"'
+			offset++
+			continue
+		}
+		var r rune
+		var width int
+		switch text[offset] {
+		case '"':
+			inString = false
+			offset++
+			continue
+		case '\\':
+			var ok bool
+			r, width, ok = decodeJsonEscape(text[offset:])
+			if !ok {
+				return fmt.Errorf("%w at byte offset %d", ErrJsonSyntax, offset)
+			}
+		default:
+			r, width = utf8.DecodeRune(text[offset:])
+			if r == utf8.RuneError && width == 1 {
+				v := newViolation(sub, offset, utf8.RuneCount(text[:offset]), width, r)
+				return &v
+			}
+		}
+		if !subsetContains(sub, r) {
+			v := newViolation(sub, offset, utf8.RuneCount(text[:offset]), width, r)
+			return &v
+		}
+		offset += width
+	}
+	if inString {
+		return fmt.Errorf("%w at byte offset %d", ErrJsonSyntax, len(text))
+	}
+	return nil
+}
+
+// UnmarshalJson is like json.Unmarshal, but first checks every string in data, including
+// member names, with CheckJsonText, so that every string field of v, however deeply nested,
+// is known to be valid for the subset without being checked one by one. If the check fails,
+// v is left untouched.
+func (sub *Subset) UnmarshalJson(data []byte, v any) error {
+	if err := sub.CheckJsonText(data); err != nil {
+		return err
+	}
+	return json.Unmarshal(data, v)
+}
+
+var jsonEscapes = map[byte]rune{'"': '"', '\\': '\\', '/': '/', 'b': '\b', 'f': '\f', 'n': '\n', 'r': '\r', 't': '\t'}
+
+// decodeJsonEscape decodes the escape at the start of u, combining an escaped surrogate pair
+// into one rune. A lone escaped surrogate is returned as itself, which no subset contains.
+func decodeJsonEscape(u []byte) (r rune, width int, ok bool) {
+	if len(u) < 2 {
+		return 0, 0, false
+	}
+	if u[1] != 'u' {
+		r, ok = jsonEscapes[u[1]]
+		return r, 2, ok
+	}
+	r, ok = decodeHex4(u[2:])
+	if !ok {
+		return 0, 0, false
+	}
+	if r >= 0xD800 && r <= 0xDBFF && len(u) >= 12 && u[6] == '\\' && u[7] == 'u' {
+		if lo, ok := decodeHex4(u[8:]); ok && lo >= 0xDC00 && lo <= 0xDFFF {
+			return 0x10000 + (r-0xD800)<<10 + lo - 0xDC00, 12, true
+		}
+	}
+	return r, 6, true
+}
+
+func decodeHex4(u []byte) (rune, bool) {
+	if len(u) < 4 {
+		return 0, false
+	}
+	n, err := strconv.ParseUint(string(u[:4]), 16, 16)
+	return rune(n), err == nil
+}
@@ -0,0 +1,121 @@
+package rfc9839
+
+import (
+	"encoding/json"
+	"errors"
+	"testing"
+)
+
+func TestCheckJsonValue(t *testing.T) {
+	tests := []struct {
+		doc     string
+		pointer string
+		key     bool
+		offset  int
+	}{
+		{`{"items": [{"name": "ok"}, {"name": "b\u0007d"}]}`, "/items/1/name", false, 1},
+		{`{"a": 1, "x/y~z": {"k\u0001ey": true}}`, "/x~1y~0z/k\u0001ey", true, 1},
+		{`["fine", null, 3.5, false, "\uFFFE"]`, "/4", false, 0},
+		{`"\u0000"`, "", false, 0},
+		{`{"b": "\u0001", "a": "\u0002"}`, "/a", false, 0},
+	}
+	for _, test := range tests {
+		var v any
+		if err := json.Unmarshal([]byte(test.doc), &v); err != nil {
+			t.Fatal(err)
+		}
+		err := XmlChars.CheckJsonValue(v)
+		var jv *JsonViolation
+		if !errors.As(err, &jv) {
+			t.Errorf("CheckJsonValue(%s) = %v, want a JsonViolation", test.doc, err)
+			continue
+		}
+		if jv.Pointer != test.pointer || jv.Key != test.key || jv.Offset != test.offset {
+			t.Errorf("CheckJsonValue(%s) = %q %t %d, want %q %t %d", test.doc,
+				jv.Pointer, jv.Key, jv.Offset, test.pointer, test.key, test.offset)
+		}
+		var violation *Violation
+		if !errors.As(err, &violation) || violation.Subset != XmlChars {
+			t.Errorf("CheckJsonValue(%s) doesn't unwrap to a Violation", test.doc)
+		}
+	}
+
+	var v any
+	if err := json.Unmarshal([]byte(`{"a": ["b", {"c": "d\u0085"}], "e": 1}`), &v); err != nil {
+		t.Fatal(err)
+	}
+	if err := XmlChars.CheckJsonValue(v); err != nil {
+		t.Errorf("CheckJsonValue: %v", err)
+	}
+	err := Assignables.CheckJsonValue(v)
+	if err == nil || err.Error() != `rfc9839: U+0085 (legacy control) at byte offset 1 is not in Assignables, in the value at "/a/1/c"` {
+		t.Errorf("CheckJsonValue: %v", err)
+	}
+	err = Assignables.CheckJsonValue(map[string]any{"\x7f": 1})
+	if err == nil || err.Error() != `rfc9839: U+007F (legacy control) at byte offset 0 is not in Assignables, in the member name at "/\x7f"` {
+		t.Errorf("CheckJsonValue: %v", err)
+	}
+}
+
+func TestCheckJsonText(t *testing.T) {
+	tests := []struct {
+		in   string
+		want *Violation
+	}{
+		{`{}`, nil},
+		{`{"name": "Tim", "list": [1, "two\n", "é😀\/\""]}`, nil},
+		{`{"a": "\uD800"}`, &Violation{Offset: 7, Index: 7, Width: 6, Rune: 0xD800, Subset: XmlChars, Problem: Surrogate}},
+		{`{"a": "\uDE00\uD83D"}`, &Violation{Offset: 7, Index: 7, Width: 6, Rune: 0xDE00, Subset: XmlChars, Problem: Surrogate}},
+		{`["ok", "\b"]`, &Violation{Offset: 8, Index: 8, Width: 2, Rune: 8, Subset: XmlChars, Problem: LegacyControl}},
+		{`{"\u0001": 1}`, &Violation{Offset: 2, Index: 2, Width: 6, Rune: 1, Subset: XmlChars, Problem: LegacyControl}},
+		{`["\uFFFE"]`, &Violation{Offset: 2, Index: 2, Width: 6, Rune: 0xFFFE, Subset: XmlChars, Problem: Noncharacter}},
+		{`["\uD83D\uDE00 \uDBFF\uDFFF"]`, nil},
+		{"[\"é\x01\"]", &Violation{Offset: 4, Index: 3, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl}},
+		{"[\"a\xff\"]", &Violation{Offset: 3, Index: 3, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+	}
+	for _, test := range tests {
+		err := XmlChars.CheckJsonText([]byte(test.in))
+		var got *Violation
+		if errors.As(err, &got) != (test.want != nil) || (test.want != nil && *got != *test.want) {
+			t.Errorf("CheckJsonText(%s) = %v, want %v", test.in, err, test.want)
+		}
+	}
+
+	for _, in := range []string{`["\x"]`, `["\u12"]`, `["\u12G4"]`, `["unterminated`, `["\`} {
+		if err := XmlChars.CheckJsonText([]byte(in)); !errors.Is(err, ErrJsonSyntax) {
+			t.Errorf("CheckJsonText(%s) = %v, want ErrJsonSyntax", in, err)
+		}
+	}
+}
+
+func TestUnmarshalJson(t *testing.T) {
+	type address struct {
+		Street string
+		Tags   []string
+	}
+	type person struct {
+		Name    string
+		Aliases map[string]string
+		Home    *address
+	}
+	var p person
+	err := Assignables.UnmarshalJson([]byte(`{"Name": "Tim", "Home": {"Street": "Main", "Tags": ["é"]}}`), &p)
+	if err != nil || p.Name != "Tim" || p.Home.Tags[0] != "é" {
+		t.Fatalf("UnmarshalJson = %+v, %v", p, err)
+	}
+
+	p = person{}
+	var v *Violation
+	err = Assignables.UnmarshalJson([]byte(`{"Name": "Tim", "Home": {"Tags": ["ok", "\u0007"]}}`), &p)
+	if !errors.As(err, &v) || v.Rune != 7 || p.Name != "" {
+		t.Errorf("UnmarshalJson of a bad nested string = %v, %+v", err, p)
+	}
+	err = Assignables.UnmarshalJson([]byte(`{"Aliases": {"\uFDD0": "x"}}`), &p)
+	if !errors.As(err, &v) || v.Rune != 0xFDD0 {
+		t.Errorf("UnmarshalJson of a bad member name = %v", err)
+	}
+	var syntax *json.SyntaxError
+	if err := Assignables.UnmarshalJson([]byte(`{"Name": }`), &p); !errors.As(err, &syntax) {
+		t.Errorf("UnmarshalJson of bad JSON = %v", err)
+	}
+}
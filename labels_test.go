@@ -0,0 +1,56 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestRuneLabel(t *testing.T) {
+	tests := []struct {
+		r    rune
+		want string
+	}{
+		{0, "NULL"},
+		{7, "BELL"},
+		{0x1F, "INFORMATION SEPARATOR ONE"},
+		{0x7F, "DELETE"},
+		{0x85, "NEXT LINE"},
+		{0x9F, "APPLICATION PROGRAM COMMAND"},
+		{0xD800, "<surrogate-D800>"},
+		{0xFDD0, "<noncharacter-FDD0>"},
+		{0x10FFFF, "<noncharacter-10FFFF>"},
+		{0xE000, "<private-use-E000>"},
+		{0x10FFFD, "<private-use-10FFFD>"},
+		{'a', ""},
+		{0xA0, ""},
+		{-1, ""},
+	}
+	for _, test := range tests {
+		if got := RuneLabel(test.r); got != test.want {
+			t.Errorf("RuneLabel(%X) = %q, want %q", test.r, got, test.want)
+		}
+	}
+	// every legacy control has a name
+	for r := rune(0); r < 0xA0; r++ {
+		if IsLegacyControl(r) && RuneLabel(r) == "" {
+			t.Errorf("U+%04X has no label", r)
+		}
+	}
+}
+
+func TestViolationErrorLabel(t *testing.T) {
+	v := XmlChars.FirstInvalidString("ding\a")
+	if got, want := v.LabeledError(), "rfc9839: U+0007 BELL (legacy control) at byte offset 4 is not in XmlChars"; got != want {
+		t.Errorf("LabeledError() = %q, want %q", got, want)
+	}
+	if got, want := v.Error(), "rfc9839: U+0007 (legacy control) at byte offset 4 is not in XmlChars"; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+	custom := NewSubsetBuilder(Assignables).DenyRange(0xE000, 0xF8FF).Build()
+	v = custom.FirstInvalidString("\uE000")
+	if got, want := v.LabeledError(), "rfc9839: U+E000 <private-use-E000> at byte offset 0 is not in Assignables (modified)"; got != want {
+		t.Errorf("LabeledError() = %q, want %q", got, want)
+	}
+	if got, want := v.Error(), "rfc9839: U+E000 at byte offset 0 is not in Assignables (modified)"; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+}
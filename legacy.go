@@ -0,0 +1,37 @@
+package rfc9839
+
+// windows1252High maps the bytes 0x80 through 0x9F of Windows-1252 to runes, as the WHATWG
+// Encoding Standard defines them. The five bytes which Windows-1252 leaves undefined map to
+// the C1 controls with the same values.
+var windows1252High = [32]rune{
+	0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+	0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008D, 0x017D, 0x008F,
+	0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+	0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+}
+
+// FirstInvalidLatin1 is like FirstInvalidUtf8, but for ISO 8859-1, in which each byte is the
+// code point with the same value, so that legacy feeds needn't be transcoded just to be
+// checked. Every Violation has a Width of 1. Note that the WHATWG Encoding Standard, and so
+// web content, treats text labeled ISO-8859-1 as Windows-1252.
+func (sub *Subset) FirstInvalidLatin1(u []byte) *Violation {
+	return sub.firstInvalidDecoded(u, decodeLatin1)
+}
+
+// FirstInvalidWindows1252 is like FirstInvalidLatin1, but for Windows-1252, which differs
+// from ISO 8859-1 in giving the bytes 0x80 through 0x9F printable characters such as € and ’
+// in place of most of the C1 controls.
+func (sub *Subset) FirstInvalidWindows1252(u []byte) *Violation {
+	return sub.firstInvalidDecoded(u, decodeWindows1252)
+}
+
+func decodeLatin1(u []byte) (rune, int) {
+	return rune(u[0]), 1
+}
+
+func decodeWindows1252(u []byte) (rune, int) {
+	if u[0] >= 0x80 && u[0] <= 0x9F {
+		return windows1252High[u[0]-0x80], 1
+	}
+	return rune(u[0]), 1
+}
@@ -0,0 +1,46 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestFirstInvalidLatin1(t *testing.T) {
+	tests := []struct {
+		in      string
+		latin1  *Violation
+		windows *Violation
+	}{
+		{"", nil, nil},
+		{"caf\xe9 \xff", nil, nil},
+		{"\x80 5", &Violation{Offset: 0, Index: 0, Width: 1, Rune: 0x80, Subset: Assignables, Problem: LegacyControl}, nil},
+		{"it\x92s", &Violation{Offset: 2, Index: 2, Width: 1, Rune: 0x92, Subset: Assignables, Problem: LegacyControl}, nil},
+		{"a\x81", &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0x81, Subset: Assignables, Problem: LegacyControl},
+			&Violation{Offset: 1, Index: 1, Width: 1, Rune: 0x81, Subset: Assignables, Problem: LegacyControl}},
+		{"ab\x00", &Violation{Offset: 2, Index: 2, Width: 1, Rune: 0, Subset: Assignables, Problem: LegacyControl},
+			&Violation{Offset: 2, Index: 2, Width: 1, Rune: 0, Subset: Assignables, Problem: LegacyControl}},
+	}
+	for _, test := range tests {
+		got := Assignables.FirstInvalidLatin1([]byte(test.in))
+		if (got == nil) != (test.latin1 == nil) || (got != nil && *got != *test.latin1) {
+			t.Errorf("FirstInvalidLatin1(%q) = %v, want %v", test.in, got, test.latin1)
+		}
+		got = Assignables.FirstInvalidWindows1252([]byte(test.in))
+		if (got == nil) != (test.windows == nil) || (got != nil && *got != *test.windows) {
+			t.Errorf("FirstInvalidWindows1252(%q) = %v, want %v", test.in, got, test.windows)
+		}
+	}
+}
+
+func TestDecodeWindows1252(t *testing.T) {
+	for b := 0; b < 0x100; b++ {
+		r, width := decodeWindows1252([]byte{byte(b)})
+		if width != 1 || ((b < 0x80 || b > 0x9F) && r != rune(b)) {
+			t.Fatalf("decodeWindows1252(%X) = %X, %d", b, r, width)
+		}
+	}
+	for b, want := range map[byte]rune{0x80: '€', 0x92: '’', 0x9F: 'Ÿ', 0x8D: 0x8D} {
+		if r, _ := decodeWindows1252([]byte{b}); r != want {
+			t.Errorf("decodeWindows1252(%X) = %X, want %X", b, r, want)
+		}
+	}
+}
@@ -0,0 +1,30 @@
+//go:build rfc9839_bitmap
+
+package rfc9839
+
+// with the rfc9839_bitmap build tag, each built-in subset gets a bitmap of its BMP code
+// points at startup, so that looking one up costs a shift and a mask whatever the subset.
+// That's 8KB per subset. The astral planes, where the subsets have few ranges, are still
+// searched. It's opt-in rather than the default because that memory and startup work is paid
+// for every built-in subset, used or not; see the README.
+
+const bmpWords = 0x10000 / 64
+
+func init() {
+	for _, sub := range builtinSubsets {
+		bmp := make([]uint64, bmpWords)
+		for _, pair := range sub.pairs {
+			for r := max(pair.lo, 0); r <= min(pair.hi, 0xFFFF); r++ {
+				bmp[r>>6] |= 1 << (r & 63)
+			}
+		}
+		sub.bmp = bmp
+	}
+}
+
+func subsetContains(sub *Subset, r rune) bool {
+	if uint32(r) <= 0xFFFF && sub.bmp != nil {
+		return sub.bmp[r>>6]&(1<<(r&63)) != 0
+	}
+	return searchPairs(sub.pairs, r)
+}
@@ -0,0 +1,16 @@
+//go:build !rfc9839_sorted && !rfc9839_bitmap
+
+package rfc9839
+
+func pairContains(pair runePair, r rune) bool {
+	return r >= pair.lo && r <= pair.hi
+}
+
+func subsetContains(sub *Subset, r rune) bool {
+	for _, pair := range sub.pairs {
+		if pairContains(pair, r) {
+			return true
+		}
+	}
+	return false
+}
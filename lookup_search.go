@@ -0,0 +1,35 @@
+//go:build rfc9839_sorted || rfc9839_bitmap
+
+package rfc9839
+
+import (
+	"sort"
+)
+
+// with the rfc9839_sorted or rfc9839_bitmap build tags, the subsets' pairs are sorted into
+// numeric order at startup so that they can be searched with a binary search, rather than
+// scanned in order of estimated traffic.
+
+func init() {
+	for _, sub := range builtinSubsets {
+		pairs := sub.pairs
+		sort.Slice(pairs, func(i, j int) bool { return pairs[i].lo < pairs[j].lo })
+	}
+}
+
+func searchPairs(pairs []runePair, r rune) bool {
+	lo, hi := 0, len(pairs)
+	for lo < hi {
+		mid := int(uint(lo+hi) >> 1)
+		pair := pairs[mid]
+		switch {
+		case r < pair.lo:
+			hi = mid
+		case r > pair.hi:
+			lo = mid + 1
+		default:
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,11 @@
+//go:build rfc9839_sorted && !rfc9839_bitmap
+
+package rfc9839
+
+// the binary search costs a few comparisons on the commonest characters but bounds the cost
+// of the rarer ones, notably in the astral planes of Assignables. It's opt-in rather than the
+// default until benchmarks show it beating the linear scan; see the README.
+
+func subsetContains(sub *Subset, r rune) bool {
+	return searchPairs(sub.pairs, r)
+}
@@ -0,0 +1,54 @@
+package rfc9839
+
+import (
+	"sync"
+	"unicode/utf8"
+)
+
+// minParallelChunk is the smallest piece of input worth handing to a goroutine.
+const minParallelChunk = 64 * 1024
+
+// FirstInvalidUtf8Parallel is like FirstInvalidUtf8, but for very large inputs splits u into
+// pieces and checks them with up to workers goroutines. The result is the same as
+// FirstInvalidUtf8's, including Index, whatever the number of workers. A typical choice of
+// workers is runtime.GOMAXPROCS(0).
+func (sub *Subset) FirstInvalidUtf8Parallel(u []byte, workers int) *Violation {
+	workers = min(workers, len(u)/minParallelChunk)
+	if workers <= 1 {
+		return sub.FirstInvalidUtf8(u)
+	}
+
+	// split at rune boundaries; no earlier sequence can claim a byte which doesn't
+	// continue one, so decoding each piece separately agrees with decoding them all
+	starts := make([]int, 0, workers+1)
+	for i := 0; i < workers; i++ {
+		start := i * len(u) / workers
+		for start < len(u) && !utf8.RuneStart(u[start]) {
+			start++
+		}
+		if len(starts) == 0 || start > starts[len(starts)-1] {
+			starts = append(starts, start)
+		}
+	}
+	starts = append(starts, len(u))
+
+	found := make([]*Violation, len(starts)-1)
+	var wg sync.WaitGroup
+	for i := range found {
+		wg.Add(1)
+		go func(i int) {
+			defer wg.Done()
+			found[i] = sub.FirstInvalidUtf8(u[starts[i]:starts[i+1]])
+		}(i)
+	}
+	wg.Wait()
+
+	for i, v := range found {
+		if v != nil {
+			v.Offset += starts[i]
+			v.Index += utf8.RuneCount(u[:starts[i]])
+			return v
+		}
+	}
+	return nil
+}
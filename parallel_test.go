@@ -0,0 +1,26 @@
+package rfc9839
+
+import (
+	"bytes"
+	"testing"
+)
+
+func TestFirstInvalidUtf8Parallel(t *testing.T) {
+	// long enough to be split, with runes straddling every likely split point
+	clean := bytes.Repeat([]byte("ab€\U0001F600"), 3*minParallelChunk/9)
+	if v := Assignables.FirstInvalidUtf8Parallel(clean, 4); v != nil {
+		t.Fatalf("FirstInvalidUtf8Parallel(clean) = %v", v)
+	}
+	for _, bad := range [][]byte{{0}, {0xFF}, {0xE2, 0x82}, []byte("\U0001FFFF")} {
+		for _, at := range []int{0, 1, len(clean) / 3, len(clean)/2 + 1, len(clean) - 1} {
+			u := append(append(append([]byte{}, clean[:at]...), bad...), clean[at:]...)
+			want := Assignables.FirstInvalidUtf8(u)
+			for _, workers := range []int{0, 1, 2, 3, 8} {
+				got := Assignables.FirstInvalidUtf8Parallel(u, workers)
+				if got == nil || *got != *want {
+					t.Errorf("%q at %d with %d workers = %v, want %v", bad, at, workers, got, want)
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,82 @@
+package rfc9839
+
+import (
+	"unicode/utf8"
+)
+
+// A PartialValidator checks input which arrives in chunks, reporting exactly how much of each
+// chunk it has consumed, so that the caller can manage its own buffers. It never consumes part
+// of a rune. A PartialValidator is not safe for concurrent use.
+type PartialValidator struct {
+	sub    *Subset
+	offset int
+	index  int
+}
+
+// Progress is the result of feeding a chunk to a PartialValidator. Consumed is the number of
+// bytes at the start of the chunk which were checked and found valid. If Violation is not nil,
+// it describes the bytes which follow them, with its Offset and Index counted from the start of
+// the input rather than of the chunk. If NeedMore is true, the chunk ended part way through a
+// rune, whose bytes should be fed again at the start of the next chunk.
+type Progress struct {
+	Consumed  int
+	Violation *Violation
+	NeedMore  bool
+}
+
+// NewPartialValidator returns a PartialValidator for sub.
+func NewPartialValidator(sub *Subset) *PartialValidator {
+	return &PartialValidator{sub: sub}
+}
+
+// Feed checks the next chunk of input. Once it has reported a Violation, the
+// PartialValidator should not be fed again.
+func (pv *PartialValidator) Feed(chunk []byte) Progress {
+	return pv.feed(chunk, false)
+}
+
+// Finish checks what is left of the input after the last call to Feed, usually the bytes of a
+// rune which was reported as needing more, and which, now that the input has ended, are
+// invalid UTF-8. It returns nil if rest is empty or valid.
+func (pv *PartialValidator) Finish(rest []byte) *Violation {
+	return pv.feed(rest, true).Violation
+}
+
+func (pv *PartialValidator) feed(chunk []byte, final bool) Progress {
+	consumed := 0
+	for consumed < len(chunk) {
+		if !final && !utf8.FullRune(chunk[consumed:]) {
+			pv.advance(chunk[:consumed])
+			return Progress{Consumed: consumed, NeedMore: true}
+		}
+		r, width, ok := decodeInSubset(chunk[consumed:], pv.sub)
+		if !ok {
+			pv.advance(chunk[:consumed])
+			v := newViolation(pv.sub, pv.offset, pv.index, width, r)
+			return Progress{Consumed: consumed, Violation: &v}
+		}
+		consumed += width
+	}
+	pv.advance(chunk)
+	return Progress{Consumed: consumed}
+}
+
+// advance moves the PartialValidator's position past the valid bytes in consumed.
+func (pv *PartialValidator) advance(consumed []byte) {
+	pv.offset += len(consumed)
+	pv.index += utf8.RuneCount(consumed)
+}
+
+// ValidPrefixLen returns the length of the longest prefix of u which is valid for the subset
+// and doesn't end part way through a rune, so that a buffer can be cut there safely.
+func (sub *Subset) ValidPrefixLen(u []byte) int {
+	index := 0
+	for index < len(u) && utf8.FullRune(u[index:]) {
+		_, width, ok := decodeInSubset(u[index:], sub)
+		if !ok {
+			break
+		}
+		index += width
+	}
+	return index
+}
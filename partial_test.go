@@ -0,0 +1,79 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestPartialValidator(t *testing.T) {
+	pv := NewPartialValidator(XmlChars)
+	euro := []byte("€")
+
+	// a chunk ending part way through a rune
+	got := pv.Feed(append([]byte("ab"), euro[:2]...))
+	if got != (Progress{Consumed: 2, NeedMore: true}) {
+		t.Fatalf("Feed = %+v", got)
+	}
+	// the caller re-feeds the unconsumed bytes with the next chunk
+	got = pv.Feed(append(euro, "cd\x01e"...))
+	want := Violation{Offset: 7, Index: 5, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl}
+	if got.Consumed != 5 || got.NeedMore || got.Violation == nil || *got.Violation != want {
+		t.Fatalf("Feed = %+v, %v", got, got.Violation)
+	}
+
+	// input which ends part way through a rune
+	pv = NewPartialValidator(XmlChars)
+	if got := pv.Feed([]byte("abc")); got != (Progress{Consumed: 3}) {
+		t.Fatalf("Feed = %+v", got)
+	}
+	if got := pv.Feed(euro[:2]); got != (Progress{Consumed: 0, NeedMore: true}) {
+		t.Fatalf("Feed = %+v", got)
+	}
+	v := pv.Finish(euro[:2])
+	if v == nil || !v.BadUtf8() || v.Offset != 3 || v.Index != 3 {
+		t.Fatalf("Finish = %v", v)
+	}
+	if v := NewPartialValidator(XmlChars).Finish(nil); v != nil {
+		t.Errorf("Finish(nil) = %v", v)
+	}
+
+	// one byte at a time agrees with the one-shot check
+	for _, in := range []string{"", "plain", "café \U0001F600", "x\U0001FFFF", "a\xffb"} {
+		pv := NewPartialValidator(Assignables)
+		var pending []byte
+		var found *Violation
+		for i := 0; i < len(in) && found == nil; i++ {
+			pending = append(pending, in[i])
+			progress := pv.Feed(pending)
+			pending = pending[progress.Consumed:]
+			found = progress.Violation
+		}
+		if found == nil {
+			found = pv.Finish(pending)
+		}
+		want := Assignables.FirstInvalidString(in)
+		if (found == nil) != (want == nil) || (found != nil && *found != *want) {
+			t.Errorf("byte-at-a-time %q = %v, want %v", in, found, want)
+		}
+	}
+}
+
+func TestValidPrefixLen(t *testing.T) {
+	tests := []struct {
+		in   string
+		want int
+	}{
+		{"", 0},
+		{"abc", 3},
+		{"ab\x00cd", 2},
+		{"ab\xffcd", 2},
+		{"ab€", 5},
+		{"ab\xe2\x82", 2},
+		{"ab\xe2\x82x", 2},
+		{"\U0001F600\xf0\x9f", 4},
+	}
+	for _, test := range tests {
+		if got := XmlChars.ValidPrefixLen([]byte(test.in)); got != test.want {
+			t.Errorf("ValidPrefixLen(%q) = %d, want %d", test.in, got, test.want)
+		}
+	}
+}
@@ -0,0 +1,81 @@
+package rfc9839
+
+import (
+	"unicode"
+)
+
+// A Problem is the reason, in terms of the categories RFC 9839 describes, that a code point
+// is problematic.
+type Problem int
+
+const (
+	NotProblematic Problem = iota // none of the below, although a subset may still exclude it
+	LegacyControl                 // a control code other than tab, newline, and carriage return
+	Surrogate                     // U+D800 through U+DFFF
+	Noncharacter                  // U+FDD0 through U+FDEF, and the last two code points of each plane
+	InvalidUtf8                   // not a code point at all, but a byte of invalid UTF-8
+	OutOfRange                    // a negative rune, or one greater than unicode.MaxRune
+)
+
+var problemNames = []string{
+	NotProblematic: "not problematic",
+	LegacyControl:  "legacy control",
+	Surrogate:      "surrogate",
+	Noncharacter:   "noncharacter",
+	InvalidUtf8:    "invalid UTF-8",
+	OutOfRange:     "out of range",
+}
+
+func (p Problem) String() string {
+	if p < 0 || int(p) >= len(problemNames) {
+		return "unknown problem"
+	}
+	return problemNames[p]
+}
+
+// IsLegacyControl reports whether r is a control code other than tab, newline, and carriage
+// return: U+0000 through U+001F, U+007F (DEL), and U+0080 through U+009F (the C1 controls).
+func IsLegacyControl(r rune) bool {
+	if r < 0x20 {
+		return r >= 0 && r != '\t' && r != '\n' && r != '\r'
+	}
+	return r >= 0x7F && r <= 0x9F
+}
+
+// IsSurrogate reports whether r is a surrogate, U+D800 through U+DFFF, which are reserved for
+// UTF-16 and are not scalars.
+func IsSurrogate(r rune) bool {
+	return r >= 0xD800 && r <= 0xDFFF
+}
+
+// IsNoncharacter reports whether r is a noncharacter: U+FDD0 through U+FDEF, and the last
+// two code points of each plane.
+func IsNoncharacter(r rune) bool {
+	if r < 0 || r > unicode.MaxRune {
+		return false
+	}
+	return r >= 0xFDD0 && r <= 0xFDEF || r&0xFFFE == 0xFFFE
+}
+
+// These three categories are the building blocks of the subsets. Scalars is every code point
+// which is not a surrogate, Assignables is Scalars less the legacy controls and the
+// noncharacters, and XmlChars is Scalars less the C0 legacy controls and U+FFFE and U+FFFF.
+// The subsets' range tables stay authoritative, since the lookups are built from them, and
+// TestDerivedSubsets checks that they agree with these predicates at every code point.
+
+// Classify returns which of the categories of problematic code points r falls into, so that
+// error messages can say why it was rejected. It returns NotProblematic for code points in
+// none of the categories, including tab, newline, and carriage return.
+func Classify(r rune) Problem {
+	switch {
+	case r < 0 || r > unicode.MaxRune:
+		return OutOfRange
+	case IsLegacyControl(r):
+		return LegacyControl
+	case IsSurrogate(r):
+		return Surrogate
+	case IsNoncharacter(r):
+		return Noncharacter
+	}
+	return NotProblematic
+}
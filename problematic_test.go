@@ -0,0 +1,33 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestDerivedSubsets(t *testing.T) {
+	for r := rune(-1); r <= 0x110000; r++ {
+		scalar := r >= 0 && r <= 0x10FFFF && !IsSurrogate(r)
+		assignable := scalar && !IsLegacyControl(r) && !IsNoncharacter(r)
+		xml := scalar && !(r < 0x20 && IsLegacyControl(r)) && r != 0xFFFE && r != 0xFFFF
+		if Scalars.ValidRune(r) != scalar {
+			t.Fatalf("Scalars disagrees with the predicates at %X", r)
+		}
+		if Assignables.ValidRune(r) != assignable {
+			t.Fatalf("Assignables disagrees with the predicates at %X", r)
+		}
+		if XmlChars.ValidRune(r) != xml {
+			t.Fatalf("XmlChars disagrees with the predicates at %X", r)
+		}
+	}
+}
+
+func TestPredicatesOutOfRange(t *testing.T) {
+	for _, r := range []rune{-1, -0x80000000, 0x110000, 0x7FFFFFFF} {
+		if IsLegacyControl(r) || IsSurrogate(r) || IsNoncharacter(r) {
+			t.Errorf("%X is out of range, but a predicate accepted it", r)
+		}
+		if Classify(r) != OutOfRange {
+			t.Errorf("Classify(%X) = %v, want OutOfRange", r, Classify(r))
+		}
+	}
+}
@@ -0,0 +1,32 @@
+package rfc9839
+
+import (
+	"fmt"
+)
+
+// A ProblemDetail describes a Violation in the form of an RFC 7807 problem details object,
+// ready to be marshaled with encoding/json as the body of an HTTP error response.
+type ProblemDetail struct {
+	Type      string `json:"type"`
+	Title     string `json:"title"`
+	Detail    string `json:"detail"`
+	Offset    int    `json:"offset"`              // byte offset of the violation
+	CodePoint string `json:"codePoint,omitempty"` // like "U+0000", omitted for invalid UTF-8
+}
+
+// ProblemDetailType is the Type of every ProblemDetail.
+const ProblemDetailType = "https://www.rfc-editor.org/rfc/rfc9839"
+
+// ProblemDetail returns v as a ProblemDetail, whose Detail is v's error message.
+func (v *Violation) ProblemDetail() ProblemDetail {
+	pd := ProblemDetail{
+		Type:   ProblemDetailType,
+		Title:  "Invalid character",
+		Detail: v.Error(),
+		Offset: v.Offset,
+	}
+	if !v.BadUtf8() {
+		pd.CodePoint = fmt.Sprintf("U+%04X", v.Rune)
+	}
+	return pd
+}
@@ -0,0 +1,24 @@
+package rfc9839
+
+import (
+	"encoding/json"
+	"testing"
+)
+
+func TestProblemDetail(t *testing.T) {
+	tests := []struct {
+		in   string
+		want string
+	}{
+		{"ab\x01", `{"type":"https://www.rfc-editor.org/rfc/rfc9839","title":"Invalid character",` +
+			`"detail":"rfc9839: U+0001 (legacy control) at byte offset 2 is not in XmlChars","offset":2,"codePoint":"U+0001"}`},
+		{"a\xff", `{"type":"https://www.rfc-editor.org/rfc/rfc9839","title":"Invalid character",` +
+			`"detail":"rfc9839: invalid UTF-8 at byte offset 1","offset":1}`},
+	}
+	for _, test := range tests {
+		got, err := json.Marshal(XmlChars.FirstInvalidString(test.in).ProblemDetail())
+		if err != nil || string(got) != test.want {
+			t.Errorf("ProblemDetail(%q) = %s, %v, want %s", test.in, got, err, test.want)
+		}
+	}
+}
@@ -0,0 +1,160 @@
+package rfc9839
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+	"unicode"
+)
+
+// A Range is the inclusive range of code points from Lo to Hi.
+type Range struct {
+	Lo rune
+	Hi rune
+}
+
+// SortedRanges returns the subset as ranges which are sorted in ascending order, don't
+// overlap, and don't touch, so that adjacent ranges are merged. Unlike the subset's internal
+// tables, which are ordered for fast lookup, this canonical form is guaranteed, and suits set
+// algebra. The caller owns the returned slice.
+func (sub *Subset) SortedRanges() []Range {
+	ranges := make([]Range, len(sub.pairs))
+	for i, pair := range sub.pairs {
+		ranges[i] = Range{pair.lo, pair.hi}
+	}
+	return normalizeRanges(ranges)
+}
+
+// IsSubsetOf reports whether every code point in the subset is also in other, in which case
+// input which is valid for the subset needs no checking against other. Among the built-in
+// subsets, Assignables is a subset of XmlChars, which is a subset of Scalars.
+func (sub *Subset) IsSubsetOf(other *Subset) bool {
+	outer := other.SortedRanges()
+	i := 0
+	for _, r := range sub.SortedRanges() {
+		// since outer's ranges don't touch, r must fit inside just one of them
+		for i < len(outer) && outer[i].Hi < r.Lo {
+			i++
+		}
+		if i == len(outer) || outer[i].Lo > r.Lo || outer[i].Hi < r.Hi {
+			return false
+		}
+	}
+	return true
+}
+
+// normalizeRanges sorts ranges and merges those which overlap or touch, in place.
+func normalizeRanges(ranges []Range) []Range {
+	sort.Slice(ranges, func(i, j int) bool { return ranges[i].Lo < ranges[j].Lo })
+	merged := ranges[:0]
+	for _, r := range ranges {
+		last := len(merged) - 1
+		if last >= 0 && r.Lo <= merged[last].Hi+1 {
+			merged[last].Hi = max(merged[last].Hi, r.Hi)
+			continue
+		}
+		merged = append(merged, r)
+	}
+	return merged
+}
+
+// RangeTable returns the subset as a unicode.RangeTable, for use with unicode.Is and the
+// other functions of the unicode package. Lookups through it are slower than ValidRune.
+func (sub *Subset) RangeTable() *unicode.RangeTable {
+	table := &unicode.RangeTable{}
+	for _, r := range sub.SortedRanges() {
+		if r.Lo <= 0xFFFF {
+			hi := min(r.Hi, 0xFFFF)
+			table.R16 = append(table.R16, unicode.Range16{Lo: uint16(r.Lo), Hi: uint16(hi), Stride: 1})
+			if r.Hi <= unicode.MaxLatin1 {
+				table.LatinOffset++
+			}
+		}
+		if r.Hi > 0xFFFF {
+			lo := max(r.Lo, 0x10000)
+			table.R32 = append(table.R32, unicode.Range32{Lo: uint32(lo), Hi: uint32(r.Hi), Stride: 1})
+		}
+	}
+	return table
+}
+
+// Abnf returns the subset as ABNF alternatives in the style of RFC 9839, such as
+// "%x9-A / %xD / %x20-D7FF", ready to follow a rule name and "=".
+func (sub *Subset) Abnf() string {
+	return sub.formatRanges("%%x%X", "%%x%X-%X", " / ", "", "")
+}
+
+// RegexpClass returns the subset as a regular expression character class, such as
+// `[\x{9}-\x{A}\x{D}]`, in the syntax of the regexp package, which PCRE and many other
+// engines also accept.
+func (sub *Subset) RegexpClass() string {
+	return sub.formatRanges(`\x{%X}`, `\x{%X}-\x{%X}`, "", "[", "]")
+}
+
+// ErrNotXml is returned by XsdPattern for a subset with code points which can't appear in an
+// XML 1.0 document, even as character references.
+var ErrNotXml = errors.New("rfc9839: subset has code points which XML 1.0 can't express")
+
+// XsdPattern returns the subset as a character class for the pattern facet of an XML Schema,
+// such as "[&#x9;-&#xA;&#xD;]". XML Schema regular expressions have no escapes for code
+// points, so it uses character references, which the XML parser expands before the schema
+// processor sees them; it must be pasted into an XML document, not used as-is. Since they are
+// expanded, the class's metacharacters - [ ] \ ^ are written with backslash escapes instead.
+// It returns ErrNotXml unless the subset is within XmlChars, so not for Scalars or Xml11Chars.
+func (sub *Subset) XsdPattern() (string, error) {
+	if !sub.IsSubsetOf(XmlChars) {
+		return "", ErrNotXml
+	}
+	var b strings.Builder
+	b.WriteString("[")
+	for _, r := range sub.SortedRanges() {
+		b.WriteString(xsdEndpoint(r.Lo))
+		if r.Lo != r.Hi {
+			b.WriteString("-")
+			b.WriteString(xsdEndpoint(r.Hi))
+		}
+	}
+	b.WriteString("]")
+	return b.String(), nil
+}
+
+func xsdEndpoint(r rune) string {
+	if strings.ContainsRune(`-[]\^`, r) {
+		return `\` + string(r)
+	}
+	return fmt.Sprintf("&#x%X;", r)
+}
+
+// Bitmap returns the subset as a bitmap of every code point, for systems in other languages
+// or in hardware which need exactly the same definition. It is (unicode.MaxRune+1)/8 bytes
+// long, and the bit for code point r is bit r%8, counting from the least significant, of
+// byte r/8; it is set if r is in the subset.
+func (sub *Subset) Bitmap() []byte {
+	bitmap := make([]byte, (unicode.MaxRune+1)/8)
+	for _, r := range sub.SortedRanges() {
+		for cp := r.Lo; cp <= r.Hi; cp++ {
+			bitmap[cp/8] |= 1 << (cp % 8)
+		}
+	}
+	return bitmap
+}
+
+// formatRanges formats each of the subset's sorted ranges with single or pair, joins them
+// with sep, and surrounds the result with prefix and suffix.
+func (sub *Subset) formatRanges(single, pair, sep, prefix, suffix string) string {
+	var b strings.Builder
+	b.WriteString(prefix)
+	for i, r := range sub.SortedRanges() {
+		if i > 0 {
+			b.WriteString(sep)
+		}
+		if r.Lo == r.Hi {
+			fmt.Fprintf(&b, single, r.Lo)
+		} else {
+			fmt.Fprintf(&b, pair, r.Lo, r.Hi)
+		}
+	}
+	b.WriteString(suffix)
+	return b.String()
+}
@@ -0,0 +1,143 @@
+package rfc9839
+
+import (
+	"regexp"
+	"slices"
+	"testing"
+	"unicode"
+)
+
+func TestSortedRanges(t *testing.T) {
+	tests := []struct {
+		sub  *Subset
+		want []Range
+	}{
+		{Scalars, []Range{{0, 0xD7FF}, {0xE000, 0x10FFFF}}},
+		{XmlChars, []Range{{0x9, 0xA}, {0xD, 0xD}, {0x20, 0xD7FF}, {0xE000, 0xFFFD}, {0x10000, 0x10FFFF}}},
+		{Xml11Chars, []Range{{0x1, 0xD7FF}, {0xE000, 0xFFFD}, {0x10000, 0x10FFFF}}},
+	}
+	for _, test := range tests {
+		if got := test.sub.SortedRanges(); !slices.Equal(got, test.want) {
+			t.Errorf("%s.SortedRanges() = %v, want %v", test.sub, got, test.want)
+		}
+	}
+
+	// the contract, and agreement with the tables, for every built-in subset
+	for _, sub := range builtinSubsets {
+		ranges := sub.SortedRanges()
+		for i, r := range ranges {
+			if r.Lo > r.Hi || (i > 0 && r.Lo <= ranges[i-1].Hi+1) {
+				t.Fatalf("%s.SortedRanges() isn't canonical at %d: %v", sub, i, ranges)
+			}
+		}
+		i := 0
+		for r := rune(0); r <= 0x10FFFF; r++ {
+			for i < len(ranges) && r > ranges[i].Hi {
+				i++
+			}
+			in := i < len(ranges) && r >= ranges[i].Lo
+			if in != sub.ValidRune(r) {
+				t.Fatalf("%s.SortedRanges() disagrees with ValidRune at %X", sub, r)
+			}
+		}
+	}
+}
+
+func TestIsSubsetOf(t *testing.T) {
+	tests := []struct {
+		sub, other *Subset
+		want       bool
+	}{
+		{Assignables, XmlChars, true},
+		{XmlChars, Scalars, true},
+		{Assignables, Scalars, true},
+		{XmlChars, Assignables, false},
+		{Scalars, XmlChars, false},
+		{XmlCharsConservative, XmlChars, true},
+		{Assignables, XmlCharsConservative, false},
+		{XmlChars, Xml11Chars, true},
+		{Xml11Chars, XmlChars, false},
+		{Xml11LiteralChars, Xml11Chars, true},
+		{Xml11LiteralChars, XmlChars, true},
+		{NewSubsetBuilder(XmlChars).DenyRange(0xFFFD, 0xFFFD).Build(), XmlChars, true},
+		{NewSubsetBuilder(XmlChars).AllowRange(0xFFFE, 0xFFFE).Build(), XmlChars, false},
+	}
+	for _, test := range tests {
+		if got := test.sub.IsSubsetOf(test.other); got != test.want {
+			t.Errorf("%s.IsSubsetOf(%s) = %t, want %t", test.sub, test.other, got, test.want)
+		}
+	}
+	for _, sub := range builtinSubsets {
+		if !sub.IsSubsetOf(sub) {
+			t.Errorf("%s is not a subset of itself", sub)
+		}
+	}
+}
+
+func TestRangeTable(t *testing.T) {
+	for _, sub := range builtinSubsets {
+		table := sub.RangeTable()
+		for r := rune(0); r <= 0x10FFFF; r++ {
+			if unicode.Is(table, r) != sub.ValidRune(r) {
+				t.Fatalf("%s.RangeTable() disagrees with ValidRune at %X", sub, r)
+			}
+		}
+	}
+	// the tables reach past U+FFFF, so a range must be split across R16 and R32
+	table := Scalars.RangeTable()
+	if len(table.R16) != 2 || len(table.R32) != 1 || table.LatinOffset != 0 {
+		t.Errorf("Scalars.RangeTable() = %+v", table)
+	}
+	if table := XmlChars.RangeTable(); table.LatinOffset != 2 {
+		t.Errorf("XmlChars.RangeTable().LatinOffset = %d, want 2", table.LatinOffset)
+	}
+}
+
+func TestBitmap(t *testing.T) {
+	for _, sub := range builtinSubsets {
+		bitmap := sub.Bitmap()
+		if len(bitmap) != 0x110000/8 {
+			t.Fatalf("%s.Bitmap() is %d bytes", sub, len(bitmap))
+		}
+		for r := rune(0); r <= 0x10FFFF; r++ {
+			if (bitmap[r/8]&(1<<(r%8)) != 0) != sub.ValidRune(r) {
+				t.Fatalf("%s.Bitmap() disagrees with ValidRune at %X", sub, r)
+			}
+		}
+	}
+	// a spot check of the layout: U+0009, U+000A, and U+000D are bits 1, 2, and 5 of byte 1
+	if b := XmlChars.Bitmap()[1]; b != 0b00100110 {
+		t.Errorf("XmlChars.Bitmap()[1] = %08b", b)
+	}
+}
+
+func TestRangeSyntax(t *testing.T) {
+	if got := XmlChars.Abnf(); got != "%x9-A / %xD / %x20-D7FF / %xE000-FFFD / %x10000-10FFFF" {
+		t.Errorf("Abnf() = %s", got)
+	}
+	if got := Scalars.RegexpClass(); got != `[\x{0}-\x{D7FF}\x{E000}-\x{10FFFF}]` {
+		t.Errorf("RegexpClass() = %s", got)
+	}
+	if got, err := XmlChars.XsdPattern(); err != nil || got != "[&#x9;-&#xA;&#xD;&#x20;-&#xD7FF;&#xE000;-&#xFFFD;&#x10000;-&#x10FFFF;]" {
+		t.Errorf("XsdPattern() = %s, %v", got, err)
+	}
+	punctuation := NewSubsetBuilder(Scalars).DenyRange(0, unicode.MaxRune).AllowRange('-', '-').AllowRange('[', '^').AllowRange('a', 'a').Build()
+	if got, err := punctuation.XsdPattern(); err != nil || got != `[\-\[-\^&#x61;]` {
+		t.Errorf("XsdPattern() = %s, %v", got, err)
+	}
+	for _, sub := range []*Subset{Scalars, Xml11Chars} {
+		if got, err := sub.XsdPattern(); err != ErrNotXml {
+			t.Errorf("%s.XsdPattern() = %s, %v", sub, got, err)
+		}
+	}
+
+	// the regexp must agree with ValidString
+	for _, sub := range builtinSubsets {
+		re := regexp.MustCompile(`^` + sub.RegexpClass() + `*$`)
+		for _, s := range []string{"", "plain", "tab\tcr\r", "\x00", "\x7f", "\u0085", "\u2028", "\uFFFE", "\U0001FFFF", "\U0010FFFD"} {
+			if re.MatchString(s) != sub.ValidString(s) {
+				t.Errorf("%s.RegexpClass() disagrees with ValidString on %q", sub, s)
+			}
+		}
+	}
+}
@@ -0,0 +1,56 @@
+package rfc9839
+
+import (
+	"io"
+	"unicode/utf8"
+)
+
+// A ValidatingReader reads from an underlying io.Reader, checking the bytes against a subset
+// before handing them on, so that bad input is rejected before it reaches a parser. It never
+// returns the bytes of a violation or anything after them.
+type ValidatingReader struct {
+	r    io.Reader
+	pv   PartialValidator
+	held []byte
+	err  error
+}
+
+// NewValidatingReader returns a ValidatingReader which checks the bytes read from r against
+// sub.
+func NewValidatingReader(r io.Reader, sub *Subset) *ValidatingReader {
+	return &ValidatingReader{r: r, pv: PartialValidator{sub: sub}, held: make([]byte, 0, utf8.UTFMax)}
+}
+
+// Read reads into p. On finding a violation, it returns the bytes before it along with a
+// *Violation whose Offset counts from the first byte read, and every later call returns the
+// same *Violation. The bytes of a rune which is split between reads of the underlying reader
+// are held back until it is complete, so p may need to hold more than a few bytes; if it
+// can't, Read returns io.ErrShortBuffer.
+func (vr *ValidatingReader) Read(p []byte) (int, error) {
+	if len(p) == 0 || vr.err != nil {
+		return 0, vr.err
+	}
+	for {
+		// checked on every pass, since a rune split between reads can fill p with held bytes
+		if len(p) <= len(vr.held) {
+			return 0, io.ErrShortBuffer
+		}
+		k := copy(p, vr.held)
+		n, err := vr.r.Read(p[k:])
+		total := k + n
+		progress := vr.pv.Feed(p[:total])
+		switch {
+		case progress.Violation != nil:
+			vr.err = progress.Violation
+		case progress.NeedMore && err == io.EOF:
+			// the input ends part way through a rune, which Finish always reports
+			vr.err = vr.pv.Finish(p[progress.Consumed:total])
+		default:
+			vr.err = err
+			vr.held = append(vr.held[:0], p[progress.Consumed:total]...)
+		}
+		if progress.Consumed > 0 || vr.err != nil {
+			return progress.Consumed, vr.err
+		}
+	}
+}
@@ -0,0 +1,83 @@
+package rfc9839
+
+import (
+	"errors"
+	"io"
+	"strings"
+	"testing"
+	"testing/iotest"
+)
+
+func TestValidatingReader(t *testing.T) {
+	tests := []struct {
+		in   string
+		want *Violation
+	}{
+		{"", nil},
+		{"plain", nil},
+		{"café \U0001F600 and €", nil},
+		{"ok\x01", &Violation{Offset: 2, Index: 2, Width: 1, Rune: 1, Subset: Assignables, Problem: LegacyControl}},
+		{"€\U0001FFFEx", &Violation{Offset: 3, Index: 1, Width: 4, Rune: 0x1FFFE, Subset: Assignables, Problem: Noncharacter}},
+		{"cut \xe2\x82", &Violation{Offset: 4, Index: 4, Width: 1, Rune: 0xFFFD, Subset: Assignables, Problem: InvalidUtf8}},
+	}
+	for _, test := range tests {
+		// one byte at a time from the underlying reader, so runes are split between reads
+		readers := []io.Reader{strings.NewReader(test.in), iotest.OneByteReader(strings.NewReader(test.in))}
+		for _, r := range readers {
+			got, err := io.ReadAll(NewValidatingReader(r, Assignables))
+			var v *Violation
+			if test.want == nil {
+				if err != nil || string(got) != test.in {
+					t.Errorf("ReadAll(%q) = %q, %v", test.in, got, err)
+				}
+				continue
+			}
+			if !errors.As(err, &v) || *v != *test.want || string(got) != test.in[:test.want.Offset] {
+				t.Errorf("ReadAll(%q) = %q, %v, want %v", test.in, got, err, test.want)
+			}
+		}
+	}
+}
+
+func TestValidatingReaderShortBuffer(t *testing.T) {
+	vr := NewValidatingReader(strings.NewReader("a€b"), XmlChars)
+	p := make([]byte, 2)
+	if n, err := vr.Read(p); n != 1 || err != nil || p[0] != 'a' {
+		t.Fatalf("Read = %d, %v", n, err)
+	}
+	// the second byte read was the first of the euro sign, held back
+	if _, err := vr.Read(p[:1]); err != io.ErrShortBuffer {
+		t.Errorf("Read = %v, want io.ErrShortBuffer", err)
+	}
+	got, err := io.ReadAll(vr)
+	if err != nil || string(got) != "€b" {
+		t.Errorf("ReadAll = %q, %v", got, err)
+	}
+}
+
+func TestValidatingReaderShortBufferFresh(t *testing.T) {
+	// a buffer too small for the first rune, which used to make Read loop forever
+	tests := []struct {
+		in   string
+		size int
+	}{
+		{"é", 1},
+		{"€", 2},
+		{"\U0001F600", 3},
+	}
+	for _, test := range tests {
+		vr := NewValidatingReader(iotest.OneByteReader(strings.NewReader(test.in)), Assignables)
+		if n, err := vr.Read(make([]byte, test.size)); n != 0 || err != io.ErrShortBuffer {
+			t.Errorf("Read(%q) into %d bytes = %d, %v, want io.ErrShortBuffer", test.in, test.size, n, err)
+		}
+		// a bigger buffer picks up where it left off
+		got, err := io.ReadAll(vr)
+		if err != nil || string(got) != test.in {
+			t.Errorf("ReadAll(%q) = %q, %v", test.in, got, err)
+		}
+	}
+	vr := NewValidatingReader(strings.NewReader("€"), Assignables)
+	if n, err := vr.Read(make([]byte, 2)); n != 0 || err != io.ErrShortBuffer {
+		t.Errorf("Read(€) into 2 bytes = %d, %v, want io.ErrShortBuffer", n, err)
+	}
+}
@@ -0,0 +1,59 @@
+package rfc9839
+
+import (
+	"fmt"
+)
+
+// A Report summarizes every violation in some input, ready to be marshaled with encoding/json
+// as a machine-readable validation result.
+type Report struct {
+	Subset     string            `json:"subset"`               // the subset's Code, or its name if it has none
+	Length     int               `json:"length"`               // length of the input in bytes
+	Counts     map[string]int    `json:"counts,omitempty"`     // number of violations of each Problem, by name
+	Violations []ReportViolation `json:"violations,omitempty"` // in order of Offset
+}
+
+// A ReportViolation is the part of a Violation which goes in a Report.
+type ReportViolation struct {
+	Offset    int    `json:"offset"`              // byte offset of the violation
+	Width     int    `json:"width"`               // length in bytes of the violation
+	CodePoint string `json:"codePoint,omitempty"` // like "U+0000", omitted for invalid UTF-8
+	Problem   string `json:"problem"`             // the name of the Problem
+}
+
+// Valid reports whether the input had no violations.
+func (r *Report) Valid() bool {
+	return len(r.Violations) == 0
+}
+
+// ReportString returns a Report of every violation in s. Like AllInvalidString, it reports
+// each byte of invalid UTF-8 separately.
+func (sub *Subset) ReportString(s string) Report {
+	return sub.report(len(s), sub.AllInvalidString(s))
+}
+
+// ReportUtf8 is the []byte equivalent of ReportString.
+func (sub *Subset) ReportUtf8(u []byte) Report {
+	return sub.report(len(u), sub.AllInvalidUtf8(u))
+}
+
+func (sub *Subset) report(length int, violations []Violation) Report {
+	r := Report{Subset: sub.code, Length: length}
+	if r.Subset == "" {
+		r.Subset = sub.name
+	}
+	if len(violations) == 0 {
+		return r
+	}
+	r.Counts = make(map[string]int)
+	r.Violations = make([]ReportViolation, 0, len(violations))
+	for _, v := range violations {
+		rv := ReportViolation{Offset: v.Offset, Width: v.Width, Problem: v.Problem.String()}
+		if !v.BadUtf8() {
+			rv.CodePoint = fmt.Sprintf("U+%04X", v.Rune)
+		}
+		r.Counts[rv.Problem]++
+		r.Violations = append(r.Violations, rv)
+	}
+	return r
+}
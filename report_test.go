@@ -0,0 +1,48 @@
+package rfc9839
+
+import (
+	"encoding/json"
+	"testing"
+)
+
+func TestReport(t *testing.T) {
+	tests := []struct {
+		sub  *Subset
+		in   string
+		want string
+	}{
+		{XmlChars, "fine", `{"subset":"XML","length":4}`},
+		{Assignables, "a\x01\x02\xff\uFDD0", `{"subset":"ASSIGN","length":7,` +
+			`"counts":{"invalid UTF-8":1,"legacy control":2,"noncharacter":1},"violations":[` +
+			`{"offset":1,"width":1,"codePoint":"U+0001","problem":"legacy control"},` +
+			`{"offset":2,"width":1,"codePoint":"U+0002","problem":"legacy control"},` +
+			`{"offset":3,"width":1,"problem":"invalid UTF-8"},` +
+			`{"offset":4,"width":3,"codePoint":"U+FDD0","problem":"noncharacter"}]}`},
+		{XmlCharsConservative, "\u2028", `{"subset":"XML-CONSERVATIVE","length":3,` +
+			`"counts":{"not problematic":1},"violations":[` +
+			`{"offset":0,"width":3,"codePoint":"U+2028","problem":"not problematic"}]}`},
+	}
+	for _, test := range tests {
+		report := test.sub.ReportString(test.in)
+		if report.Valid() != test.sub.ValidString(test.in) {
+			t.Errorf("ReportString(%q).Valid() = %t", test.in, report.Valid())
+		}
+		got, err := json.Marshal(report)
+		if err != nil || string(got) != test.want {
+			t.Errorf("ReportString(%q) = %s, %v, want %s", test.in, got, err, test.want)
+		}
+		report = test.sub.ReportUtf8([]byte(test.in))
+		if got, err := json.Marshal(report); err != nil || string(got) != test.want {
+			t.Errorf("ReportUtf8(%q) = %s, %v, want %s", test.in, got, err, test.want)
+		}
+	}
+
+	custom := NewSubsetBuilder(XmlChars).Name("NoDigits").DenyRange('0', '9').Build()
+	if report := custom.ReportString("x"); report.Subset != "NoDigits" {
+		t.Errorf("Subset of a custom subset = %q", report.Subset)
+	}
+	unnamed := NewSubsetBuilder(XmlChars).DenyRange('0', '9').Build()
+	if report := unnamed.ReportString("x"); report.Subset != "XmlChars (modified)" {
+		t.Errorf("Subset of an unnamed custom subset = %q", report.Subset)
+	}
+}
@@ -0,0 +1,83 @@
+// Package rfc9839test generates random strings for property-testing code which handles the
+// RFC 9839 subsets, so that tests needn't encode the subsets' tables themselves.
+package rfc9839test
+
+import (
+	"math/rand/v2"
+	"strings"
+	"unicode"
+
+	"github.com/timbray/rfc9839"
+)
+
+// String returns a string of n runes, all in sub. Each rune is drawn from one of the subset's
+// ranges chosen at random, regardless of its size, so that small ranges such as tab are as
+// likely to turn up as the astral planes.
+func String(rng *rand.Rand, sub *rfc9839.Subset, n int) string {
+	ranges := encodable(sub.SortedRanges())
+	var b strings.Builder
+	for i := 0; i < n; i++ {
+		b.WriteRune(pick(rng, ranges))
+	}
+	return b.String()
+}
+
+// StringWithViolation returns a string of n valid runes with a single violation inserted at
+// random, and the byte offset of the violation. The violation is either a rune which is not
+// in sub or, half the time and whenever sub contains every scalar, a byte of invalid UTF-8.
+func StringWithViolation(rng *rand.Rand, sub *rfc9839.Subset, n int) (s string, offset int) {
+	valid := encodable(sub.SortedRanges())
+	invalid := encodable(complement(sub.SortedRanges()))
+	at := rng.IntN(n + 1)
+	var b strings.Builder
+	for i := 0; i <= n; i++ {
+		if i != at {
+			b.WriteRune(pick(rng, valid))
+			continue
+		}
+		offset = b.Len()
+		if len(invalid) == 0 || rng.IntN(2) == 0 {
+			// a byte with the high bit set, which the next rune can't complete since it
+			// starts with ASCII or a leading byte
+			b.WriteByte(byte(0x80 + rng.IntN(0x80)))
+		} else {
+			b.WriteRune(pick(rng, invalid))
+		}
+	}
+	return b.String(), offset
+}
+
+func pick(rng *rand.Rand, ranges []rfc9839.Range) rune {
+	r := ranges[rng.IntN(len(ranges))]
+	return r.Lo + rng.Int32N(r.Hi-r.Lo+1)
+}
+
+// complement returns the code points not in the canonical ranges.
+func complement(ranges []rfc9839.Range) []rfc9839.Range {
+	var out []rfc9839.Range
+	next := rune(0)
+	for _, r := range ranges {
+		if r.Lo > next {
+			out = append(out, rfc9839.Range{Lo: next, Hi: r.Lo - 1})
+		}
+		next = r.Hi + 1
+	}
+	if next <= unicode.MaxRune {
+		out = append(out, rfc9839.Range{Lo: next, Hi: unicode.MaxRune})
+	}
+	return out
+}
+
+// encodable removes the surrogates, which can't be encoded in UTF-8, from canonical ranges.
+func encodable(ranges []rfc9839.Range) []rfc9839.Range {
+	var out []rfc9839.Range
+	for _, r := range ranges {
+		if r.Lo < 0xD800 {
+			out = append(out, rfc9839.Range{Lo: r.Lo, Hi: min(r.Hi, 0xD7FF)})
+		}
+		if r.Hi > 0xDFFF {
+			out = append(out, rfc9839.Range{Lo: max(r.Lo, 0xE000), Hi: r.Hi})
+		}
+	}
+	return out
+}
@@ -0,0 +1,46 @@
+package rfc9839test
+
+import (
+	"math/rand/v2"
+	"testing"
+
+	"github.com/timbray/rfc9839"
+)
+
+var subsets = []*rfc9839.Subset{
+	rfc9839.Scalars, rfc9839.XmlChars, rfc9839.Assignables, rfc9839.XmlCharsConservative, rfc9839.Xml11Chars,
+}
+
+func TestString(t *testing.T) {
+	rng := rand.New(rand.NewPCG(1, 2))
+	for _, sub := range subsets {
+		for i := 0; i < 1000; i++ {
+			s := String(rng, sub, i%20)
+			if !sub.ValidString(s) {
+				t.Fatalf("String(%s) = %q, which is not valid", sub, s)
+			}
+		}
+	}
+}
+
+func TestStringWithViolation(t *testing.T) {
+	rng := rand.New(rand.NewPCG(3, 4))
+	for _, sub := range subsets {
+		badUtf8, badRune := 0, 0
+		for i := 0; i < 1000; i++ {
+			s, offset := StringWithViolation(rng, sub, i%20)
+			v := sub.FirstInvalidString(s)
+			if v == nil || v.Offset != offset || len(sub.AllInvalidString(s)) != 1 {
+				t.Fatalf("StringWithViolation(%s) = %q, %d, but found %v", sub, s, offset, v)
+			}
+			if v.BadUtf8() {
+				badUtf8++
+			} else {
+				badRune++
+			}
+		}
+		if badUtf8 == 0 || (badRune == 0 && sub != rfc9839.Scalars) {
+			t.Errorf("StringWithViolation(%s) made %d bad bytes and %d bad runes", sub, badUtf8, badRune)
+		}
+	}
+}
@@ -0,0 +1,275 @@
+package rfc9839
+
+import (
+	"fmt"
+	"strings"
+	"unicode/utf16"
+	"unicode/utf8"
+)
+
+type replacementKind int
+
+const (
+	// first, so that the zero Replacement is ReplacementChar rather than a substitute of U+0000
+	replaceChar replacementKind = iota
+	replaceRune
+	dropRune
+	escapeRune
+	escapeJson
+	escapeXml
+)
+
+// A Replacement is a policy for what Transform does with each rune which is not in the
+// subset, and with each byte of invalid UTF-8. The zero Replacement is ReplacementChar.
+type Replacement struct {
+	kind replacementKind
+	r    rune
+}
+
+var (
+	// ReplacementChar replaces each offending rune or byte with U+FFFD.
+	ReplacementChar = Replacement{kind: replaceChar}
+
+	// Drop removes each offending rune or byte.
+	Drop = Replacement{kind: dropRune}
+
+	// Escape replaces each offending rune with an escape like \u{1FFFE}, and each byte of
+	// invalid UTF-8 with an escape like \xFF.
+	//
+	// The escaping policies leave valid runes alone, including backslashes and ampersands, so
+	// input which already contains text like \u{0} or &#x0; can't be told apart from an
+	// escape in the output. They are for logs and other output read by people, not for
+	// output which something will decode.
+	Escape = Replacement{kind: escapeRune}
+
+	// EscapeJson replaces each offending rune with a JSON escape like \u0000, or a pair of
+	// them like \uD83F\uDFFE for runes outside the BMP. JSON can't express bytes which aren't
+	// UTF-8, so each byte of invalid UTF-8 becomes \uFFFD. Quotes and backslashes are not
+	// escaped, so the output is not a JSON string body.
+	EscapeJson = Replacement{kind: escapeJson}
+
+	// EscapeXml replaces each offending rune with an XML character reference like &#x1FFFE;,
+	// and each byte of invalid UTF-8 with &#xFFFD;. Ampersands and less-than signs are not
+	// escaped, and references to runes which aren't XML characters, such as &#x0;, are not
+	// well-formed XML, so the output is not XML content.
+	EscapeXml = Replacement{kind: escapeXml}
+)
+
+// ReplaceWith returns a Replacement which substitutes r for each offending rune or byte.
+// If r is not itself in the subset, neither will the output be.
+func ReplaceWith(r rune) Replacement {
+	if !utf8.ValidRune(r) {
+		r = utf8.RuneError
+	}
+	return Replacement{kind: replaceRune, r: r}
+}
+
+// appendTo appends the replacement for the offending rune r, which occupied width bytes
+// starting with first, to dst.
+func (p Replacement) appendTo(dst []byte, r rune, width int, first byte) []byte {
+	switch p.kind {
+	case replaceChar:
+		return utf8.AppendRune(dst, utf8.RuneError)
+	case dropRune:
+		return dst
+	case escapeRune:
+		if r == utf8.RuneError && width == 1 {
+			return fmt.Appendf(dst, `\x%02X`, first)
+		}
+		return fmt.Appendf(dst, `\u{%X}`, r)
+	case escapeJson:
+		if r == utf8.RuneError && width == 1 {
+			return append(dst, `\uFFFD`...)
+		}
+		if r1, r2 := utf16.EncodeRune(r); r1 != utf8.RuneError {
+			return fmt.Appendf(dst, `\u%04X\u%04X`, r1, r2)
+		}
+		return fmt.Appendf(dst, `\u%04X`, r)
+	case escapeXml:
+		if r == utf8.RuneError && width == 1 {
+			return append(dst, "&#xFFFD;"...)
+		}
+		return fmt.Appendf(dst, "&#x%X;", r)
+	}
+	return utf8.AppendRune(dst, p.r)
+}
+
+// size returns the number of bytes appendTo appends.
+func (p Replacement) size(r rune, width int) int {
+	switch p.kind {
+	case replaceChar:
+		return utf8.RuneLen(utf8.RuneError)
+	case dropRune:
+		return 0
+	case escapeRune:
+		if r == utf8.RuneError && width == 1 {
+			return len(`\xFF`)
+		}
+		return len(`\u{}`) + hexDigits(r)
+	case escapeJson:
+		if r >= 0x10000 && !(r == utf8.RuneError && width == 1) {
+			return len(`\uD83F\uDFFE`)
+		}
+		return len(`\uFFFD`)
+	case escapeXml:
+		if r == utf8.RuneError && width == 1 {
+			return len("&#xFFFD;")
+		}
+		return len("&#x;") + hexDigits(r)
+	}
+	return utf8.RuneLen(p.r)
+}
+
+// hexDigits returns the number of hex digits needed to write r without leading zeros.
+func hexDigits(r rune) int {
+	digits := 1
+	for v := r >> 4; v > 0; v >>= 4 {
+		digits++
+	}
+	return digits
+}
+
+// Transform returns a copy of s in which each rune that is not in the subset, and each byte
+// of invalid UTF-8, is dealt with according to policy. If s is already valid, it is returned
+// as-is, without allocating.
+func (sub *Subset) Transform(s string, policy Replacement) string {
+	index := indexInvalidString(s, sub)
+	if index < 0 {
+		return s
+	}
+	var b strings.Builder
+	b.Grow(index + sub.TransformedLen(s[index:], policy))
+	b.WriteString(s[:index])
+	var scratch [16]byte
+	for index < len(s) {
+		r, width, ok := decodeStringInSubset(s[index:], sub)
+		if ok {
+			b.WriteString(s[index : index+width])
+		} else {
+			b.Write(policy.appendTo(scratch[:0], r, width, s[index]))
+		}
+		index += width
+	}
+	return b.String()
+}
+
+// TransformUtf8 is the []byte equivalent of Transform. If u is already valid, it is returned
+// as-is rather than copied, so the caller should copy it before modifying either.
+func (sub *Subset) TransformUtf8(u []byte, policy Replacement) []byte {
+	index := 0
+	for index < len(u) {
+		_, width, ok := decodeInSubset(u[index:], sub)
+		if !ok {
+			break
+		}
+		index += width
+	}
+	if index == len(u) {
+		return u
+	}
+	n := index
+	for i := index; i < len(u); {
+		r, width, ok := decodeInSubset(u[i:], sub)
+		if ok {
+			n += width
+		} else {
+			n += policy.size(r, width)
+		}
+		i += width
+	}
+	out := make([]byte, index, n)
+	copy(out, u[:index])
+	for index < len(u) {
+		r, width, ok := decodeInSubset(u[index:], sub)
+		if ok {
+			out = append(out, u[index:index+width]...)
+		} else {
+			out = policy.appendTo(out, r, width, u[index])
+		}
+		index += width
+	}
+	return out
+}
+
+// AppendTransformed appends the transformed form of s, as produced by Transform, to dst and
+// returns the extended buffer. To allocate just once, size dst with TransformedLen.
+func (sub *Subset) AppendTransformed(dst []byte, s string, policy Replacement) []byte {
+	index := 0
+	for index < len(s) {
+		r, width, ok := decodeStringInSubset(s[index:], sub)
+		if ok {
+			dst = append(dst, s[index:index+width]...)
+		} else {
+			dst = policy.appendTo(dst, r, width, s[index])
+		}
+		index += width
+	}
+	return dst
+}
+
+// TransformedLen returns the exact length in bytes of Transform(s, policy), computed in one
+// pass without building it.
+func (sub *Subset) TransformedLen(s string, policy Replacement) int {
+	n := 0
+	index := 0
+	for index < len(s) {
+		r, width, ok := decodeStringInSubset(s[index:], sub)
+		if ok {
+			n += width
+		} else {
+			n += policy.size(r, width)
+		}
+		index += width
+	}
+	return n
+}
+
+// SanitizeString returns a copy of s in which each rune that is not in the subset, and each
+// byte of invalid UTF-8, is replaced by U+FFFD. If s is already valid, it is returned as-is.
+func (sub *Subset) SanitizeString(s string) string {
+	return sub.Transform(s, ReplacementChar)
+}
+
+// AppendSanitized appends the sanitized form of s, as produced by SanitizeString, to dst
+// and returns the extended buffer. To allocate just once, size dst with SanitizedLen.
+func (sub *Subset) AppendSanitized(dst []byte, s string) []byte {
+	return sub.AppendTransformed(dst, s, ReplacementChar)
+}
+
+// SanitizedLen returns the exact length in bytes of SanitizeString(s), computed in one pass
+// without building it.
+func (sub *Subset) SanitizedLen(s string) int {
+	return sub.TransformedLen(s, ReplacementChar)
+}
+
+// CheckOrSanitize unifies strict and lenient handling behind a flag. If sanitize is true it
+// returns SanitizeString(s); otherwise it returns s if it is valid, or a *Violation. Either
+// way, valid input is returned as-is.
+func (sub *Subset) CheckOrSanitize(s string, sanitize bool) (string, error) {
+	if sanitize {
+		return sub.SanitizeString(s), nil
+	}
+	return sub.EnsureString(s)
+}
+
+// EqualSanitized reports whether SanitizeString(a) and SanitizeString(b) are equal, comparing
+// rune by rune without building either.
+func (sub *Subset) EqualSanitized(a, b string) bool {
+	i, j := 0, 0
+	for i < len(a) && j < len(b) {
+		ra, wa, okA := decodeStringInSubset(a[i:], sub)
+		rb, wb, okB := decodeStringInSubset(b[j:], sub)
+		if !okA {
+			ra = utf8.RuneError
+		}
+		if !okB {
+			rb = utf8.RuneError
+		}
+		if ra != rb {
+			return false
+		}
+		i += wa
+		j += wb
+	}
+	return i == len(a) && j == len(b)
+}
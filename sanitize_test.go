@@ -0,0 +1,189 @@
+package rfc9839
+
+import (
+	"os"
+	"strings"
+	"testing"
+	"unsafe"
+)
+
+func TestSanitizeString(t *testing.T) {
+	tests := []struct {
+		sub  *Subset
+		in   string
+		want string
+	}{
+		{XmlChars, "", ""},
+		{XmlChars, "abc", "abc"},
+		{XmlChars, "a\x00b", "a\uFFFDb"},
+		{XmlChars, "a\uFFFEb", "a\uFFFDb"},
+		{XmlChars, "a\xffb", "a\uFFFDb"},
+		{XmlChars, "\xed\xba\xad", "\uFFFD\uFFFD\uFFFD"}, // U+DEAD
+		{Scalars, "a\x00\x7f\u0085b", "a\x00\x7f\u0085b"},
+		{Assignables, "a\x00\x7f\u0085b", "a\uFFFD\uFFFD\uFFFDb"},
+		{Assignables, "x\U0001FFFEy\U0001F600", "x\uFFFDy\U0001F600"},
+	}
+	for _, test := range tests {
+		got := test.sub.SanitizeString(test.in)
+		if got != test.want {
+			t.Errorf("SanitizeString(%q) = %q, want %q", test.in, got, test.want)
+		}
+		if n := test.sub.SanitizedLen(test.in); n != len(test.want) {
+			t.Errorf("SanitizedLen(%q) = %d, want %d", test.in, n, len(test.want))
+		}
+		appended := test.sub.AppendSanitized([]byte("prefix"), test.in)
+		if string(appended) != "prefix"+test.want {
+			t.Errorf("AppendSanitized(%q) = %q, want %q", test.in, appended, "prefix"+test.want)
+		}
+	}
+}
+
+func TestSanitizeSamples(t *testing.T) {
+	for _, name := range []string{"testdata/sample.txt", "testdata/UTF-8-test.txt"} {
+		file, err := os.ReadFile(name)
+		if err != nil {
+			t.Fatal(err)
+		}
+		s := string(file)
+		for _, sub := range []*Subset{Scalars, XmlChars, Assignables} {
+			sanitized := sub.SanitizeString(s)
+			if !sub.ValidString(sanitized) {
+				t.Errorf("sanitized %s is not valid", name)
+			}
+			if sub.ValidString(s) && sanitized != s {
+				t.Errorf("valid %s was changed by sanitizing", name)
+			}
+			size := sub.SanitizedLen(s)
+			if size != len(sanitized) {
+				t.Errorf("SanitizedLen of %s = %d, want %d", name, size, len(sanitized))
+			}
+			buf := sub.AppendSanitized(make([]byte, 0, size), s)
+			if cap(buf) != size || string(buf) != sanitized {
+				t.Errorf("AppendSanitized of %s reallocated or differs", name)
+			}
+		}
+	}
+}
+
+func TestTransform(t *testing.T) {
+	in := "a\x00b\xffc\U0001FFFEd"
+	tests := []struct {
+		sub    *Subset
+		policy Replacement
+		want   string
+	}{
+		{Assignables, ReplacementChar, "a\uFFFDb\uFFFDc\uFFFDd"},
+		{Assignables, Replacement{}, "a\uFFFDb\uFFFDc\uFFFDd"},
+		{Assignables, Drop, "abcd"},
+		{Assignables, Escape, `a\u{0}b\xFFc\u{1FFFE}d`},
+		{Assignables, EscapeJson, `a\u0000b\uFFFDc\uD83F\uDFFEd`},
+		{Assignables, EscapeXml, "a&#x0;b&#xFFFD;c&#x1FFFE;d"},
+		{Assignables, ReplaceWith('?'), "a?b?c?d"},
+		{Assignables, ReplaceWith(0xD800), "a\uFFFDb\uFFFDc\uFFFDd"},
+		{XmlChars, Escape, "a\\u{0}b\\xFFc\U0001FFFEd"},
+		{Scalars, Drop, "a\x00bc\U0001FFFEd"},
+	}
+	for _, test := range tests {
+		got := test.sub.Transform(in, test.policy)
+		if got != test.want {
+			t.Errorf("Transform(%q) = %q, want %q", in, got, test.want)
+		}
+		if n := test.sub.TransformedLen(in, test.policy); n != len(test.want) {
+			t.Errorf("TransformedLen(%q) = %d, want %d", in, n, len(test.want))
+		}
+		if appended := test.sub.AppendTransformed(nil, in, test.policy); string(appended) != test.want {
+			t.Errorf("AppendTransformed(%q) = %q, want %q", in, appended, test.want)
+		}
+		if got := test.sub.TransformUtf8([]byte(in), test.policy); string(got) != test.want || cap(got) != len(got) {
+			t.Errorf("TransformUtf8(%q) = %q, want %q", in, got, test.want)
+		}
+	}
+	// U+10FFFD is assignable, so only U+0085 is escaped
+	if got := Assignables.Transform("\u0085\U0010FFFD", Escape); got != "\\u{85}\U0010FFFD" {
+		t.Errorf("Transform = %q", got)
+	}
+	if got := Assignables.Transform("\u0085\uFDD0\x7f", EscapeJson); got != `\u0085\uFDD0\u007F` {
+		t.Errorf("Transform = %q", got)
+	}
+	if got := Assignables.Transform("\u0085\uFDD0\x7f", EscapeXml); got != "&#x85;&#xFDD0;&#x7F;" {
+		t.Errorf("Transform = %q", got)
+	}
+	// a literal escape passes through, so it reads the same as a real one
+	if got := Assignables.Transform("\\u0000\"\x00", EscapeJson); got != `\u0000"\u0000` {
+		t.Errorf("Transform = %q", got)
+	}
+	if got := Assignables.Transform("&#x0;<\x00", EscapeXml); got != "&#x0;<&#x0;" {
+		t.Errorf("Transform = %q", got)
+	}
+	if got := Assignables.Transform("\x00", Drop); got != "" {
+		t.Errorf("Transform = %q, want empty", got)
+	}
+	clean := []byte("clean")
+	if got := Assignables.TransformUtf8(clean, Escape); &got[0] != &clean[0] {
+		t.Error("TransformUtf8 should return clean input as-is")
+	}
+}
+
+func TestCheckOrSanitize(t *testing.T) {
+	for _, sanitize := range []bool{false, true} {
+		got, err := XmlChars.CheckOrSanitize("fine", sanitize)
+		if err != nil || got != "fine" {
+			t.Errorf("CheckOrSanitize(fine, %t) = %q, %v", sanitize, got, err)
+		}
+	}
+	got, err := XmlChars.CheckOrSanitize("a\x00b", true)
+	if err != nil || got != "a\uFFFDb" {
+		t.Errorf("CheckOrSanitize(true) = %q, %v", got, err)
+	}
+	got, err = XmlChars.CheckOrSanitize("a\x00b", false)
+	if err == nil || got != "" {
+		t.Errorf("CheckOrSanitize(false) = %q, %v", got, err)
+	}
+}
+
+func TestEqualSanitized(t *testing.T) {
+	tests := []struct {
+		a    string
+		b    string
+		want bool
+	}{
+		{"", "", true},
+		{"abc", "abc", true},
+		{"abc", "abd", false},
+		{"abc", "ab", false},
+		{"a\x01c", "a\x02c", true},
+		{"a\x01c", "a\xffc", true},
+		{"a\x01c", "a\uFFFDc", true},
+		{"a\x01c", "ac", false},
+		{"a\xff\xfe", "a\x01\x02", true},
+		{"a\xff\xfe", "a\x01", false},
+		{"\U0001F600\x7F", "\U0001F600\uFFFF", true},
+	}
+	for _, test := range tests {
+		got := XmlChars.EqualSanitized(test.a, test.b)
+		if got != test.want {
+			t.Errorf("EqualSanitized(%q, %q) = %t, want %t", test.a, test.b, got, test.want)
+		}
+		want := XmlChars.SanitizeString(test.a) == XmlChars.SanitizeString(test.b)
+		if got != want {
+			t.Errorf("EqualSanitized(%q, %q) = %t, but the sanitized strings say %t", test.a, test.b, got, want)
+		}
+	}
+}
+
+func TestSanitizeAllocs(t *testing.T) {
+	clean := strings.Repeat("clean input, café \U0001F600 ", 20)
+	dirty := clean + "\x00" + clean
+	var got string
+	allocs := testing.AllocsPerRun(100, func() { got = XmlChars.SanitizeString(clean) })
+	if allocs != 0 {
+		t.Errorf("SanitizeString of clean input made %v allocations, want 0", allocs)
+	}
+	if unsafe.StringData(got) != unsafe.StringData(clean) {
+		t.Error("SanitizeString of clean input didn't return its input")
+	}
+	allocs = testing.AllocsPerRun(100, func() { got = XmlChars.SanitizeString(dirty) })
+	if allocs != 1 {
+		t.Errorf("SanitizeString of dirty input made %v allocations, want 1", allocs)
+	}
+}
@@ -0,0 +1,159 @@
+package rfc9839
+
+import (
+	"unicode/utf8"
+)
+
+// A ViolationScanner steps through every Violation in some UTF-8 input, in order. Each byte
+// of invalid UTF-8 is reported as a separate Violation, just as utf8.DecodeRune steps over
+// it, so that scanning continues past malformed sequences.
+type ViolationScanner struct {
+	u         []byte
+	sub       *Subset
+	offset    int
+	index     int
+	violation Violation
+}
+
+// ScanUtf8 returns a ViolationScanner over u.
+func (sub *Subset) ScanUtf8(u []byte) *ViolationScanner {
+	return &ViolationScanner{u: u, sub: sub}
+}
+
+// Next advances the scanner to the next Violation, which is then available through the
+// Violation method. It returns false when there are no more.
+func (vs *ViolationScanner) Next() bool {
+	for vs.offset < len(vs.u) {
+		offset, index := vs.offset, vs.index
+		r, width, ok := decodeInSubset(vs.u[offset:], vs.sub)
+		vs.offset += width
+		vs.index++
+		if !ok {
+			vs.violation = newViolation(vs.sub, offset, index, width, r)
+			return true
+		}
+	}
+	return false
+}
+
+// Violation returns the Violation found by the most recent call to Next.
+func (vs *ViolationScanner) Violation() Violation {
+	return vs.violation
+}
+
+// ForEachInvalidString calls f with each Violation in s, in order, stopping early if f
+// returns false. Like ViolationScanner, it reports each byte of invalid UTF-8 separately.
+func (sub *Subset) ForEachInvalidString(s string, f func(v Violation) bool) {
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if !ok && !f(newViolation(sub, offset, index, width, r)) {
+			return
+		}
+		offset += width
+	}
+}
+
+// ForEachInvalidUtf8 is the []byte equivalent of ForEachInvalidString. Unlike ScanUtf8 and
+// AllInvalidUtf8, it allocates nothing.
+func (sub *Subset) ForEachInvalidUtf8(u []byte, f func(v Violation) bool) {
+	offset := 0
+	for index := 0; offset < len(u); index++ {
+		r, width, ok := decodeInSubset(u[offset:], sub)
+		if !ok && !f(newViolation(sub, offset, index, width, r)) {
+			return
+		}
+		offset += width
+	}
+}
+
+// A Segment is a run of bytes, from Start up to but not including End, which are either
+// all valid or all invalid.
+type Segment struct {
+	Start int
+	End   int
+	Valid bool
+}
+
+// ForEachSegment divides s into alternating valid and invalid runs, which together cover
+// all of s, and calls f with each, in order, stopping early if f returns false. An invalid
+// run may mix runes which are not in the subset with bytes of invalid UTF-8.
+func (sub *Subset) ForEachSegment(s string, f func(seg Segment) bool) {
+	seg := Segment{}
+	for seg.End < len(s) {
+		_, width, ok := decodeStringInSubset(s[seg.End:], sub)
+		if seg.End > seg.Start && ok != seg.Valid {
+			if !f(seg) {
+				return
+			}
+			seg.Start = seg.End
+		}
+		seg.Valid = ok
+		seg.End += width
+	}
+	if seg.End > seg.Start {
+		f(seg)
+	}
+}
+
+// AllInvalidString returns every Violation in s, in order, or nil if s is valid. Like
+// ViolationScanner, it reports each byte of invalid UTF-8 separately.
+func (sub *Subset) AllInvalidString(s string) []Violation {
+	var all []Violation
+	sub.ForEachInvalidString(s, func(v Violation) bool {
+		all = append(all, v)
+		return true
+	})
+	return all
+}
+
+// AllInvalidUtf8 is the []byte equivalent of AllInvalidString.
+func (sub *Subset) AllInvalidUtf8(u []byte) []Violation {
+	var all []Violation
+	for vs := sub.ScanUtf8(u); vs.Next(); {
+		all = append(all, vs.Violation())
+	}
+	return all
+}
+
+// FirstInvalidIndexed returns a Violation describing the first rune which is not in the
+// subset from seq, which yields runes paired with the caller's own byte offsets, like the
+// index and value of a range over a string; its shape is that of iter.Seq2[int, rune]. The
+// Violation's Offset is the caller's offset. It returns nil if every rune is valid. A
+// U+FFFD in seq is treated as a real U+FFFD, so seq must not use it to stand for invalid UTF-8.
+func (sub *Subset) FirstInvalidIndexed(seq func(yield func(offset int, r rune) bool)) *Violation {
+	var found *Violation
+	index := 0
+	seq(func(offset int, r rune) bool {
+		if !subsetContains(sub, r) {
+			v := newViolation(sub, offset, index, max(utf8.RuneLen(r), 0), r)
+			found = &v
+			return false
+		}
+		index++
+		return true
+	})
+	return found
+}
+
+// ValidRunes reports whether every rune from seq, whose shape is that of iter.Seq[rune], is
+// in the subset, so that text from a decoder or rope needn't be built into a string first.
+func (sub *Subset) ValidRunes(seq func(yield func(r rune) bool)) bool {
+	return sub.FirstInvalidRunes(seq) == nil
+}
+
+// FirstInvalidRunes returns a Violation describing the first rune from seq which is not in
+// the subset, or nil if there is none. Its Offset is where the rune would be if the runes
+// were encoded as UTF-8.
+func (sub *Subset) FirstInvalidRunes(seq func(yield func(r rune) bool)) *Violation {
+	offset := 0
+	return sub.FirstInvalidIndexed(func(yield func(int, rune) bool) {
+		seq(func(r rune) bool {
+			if !yield(offset, r) {
+				return false
+			}
+			offset += utf8.RuneLen(r)
+			return true
+		})
+	})
+}
@@ -0,0 +1,247 @@
+package rfc9839
+
+import (
+	"os"
+	"slices"
+	"testing"
+	"unicode/utf8"
+)
+
+func TestViolationScanner(t *testing.T) {
+	u := []byte("a\x00b\xed\xba\xad\u0085c\U0010FFFF")
+	want := []Violation{
+		{Offset: 1, Index: 1, Width: 1, Rune: 0, Problem: LegacyControl},
+		{Offset: 3, Index: 3, Width: 1, Rune: utf8.RuneError, Problem: InvalidUtf8},
+		{Offset: 4, Index: 4, Width: 1, Rune: utf8.RuneError, Problem: InvalidUtf8},
+		{Offset: 5, Index: 5, Width: 1, Rune: utf8.RuneError, Problem: InvalidUtf8},
+		{Offset: 6, Index: 6, Width: 2, Rune: 0x85, Problem: LegacyControl},
+		{Offset: 9, Index: 8, Width: 4, Rune: 0x10FFFF, Problem: Noncharacter},
+	}
+	for i := range want {
+		want[i].Subset = Assignables
+	}
+	vs := Assignables.ScanUtf8(u)
+	var got []Violation
+	for vs.Next() {
+		got = append(got, vs.Violation())
+	}
+	if len(got) != len(want) {
+		t.Fatalf("scanned %v, want %v", got, want)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Errorf("violation %d = %v, want %v", i, got[i], want[i])
+		}
+	}
+	if !got[1].BadUtf8() || got[4].BadUtf8() {
+		t.Error("BadUtf8 misreported")
+	}
+	if vs.Next() {
+		t.Error("Next should stay false at the end of input")
+	}
+
+	if Scalars.ScanUtf8([]byte("clean")).Next() {
+		t.Error("found a violation in clean input")
+	}
+	if Scalars.ScanUtf8(nil).Next() {
+		t.Error("found a violation in nil input")
+	}
+}
+
+func TestViolationScannerSample(t *testing.T) {
+	file, err := os.ReadFile("testdata/UTF-8-test.txt")
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, sub := range []*Subset{Scalars, XmlChars, Assignables} {
+		// splicing out every violation must leave valid text behind
+		var clean []byte
+		previous := 0
+		vs := sub.ScanUtf8(file)
+		for vs.Next() {
+			v := vs.Violation()
+			if v.Offset < previous {
+				t.Fatalf("violation at %d out of order", v.Offset)
+			}
+			clean = append(clean, file[previous:v.Offset]...)
+			previous = v.Offset + v.Width
+		}
+		clean = append(clean, file[previous:]...)
+		if !sub.ValidUtf8(clean) {
+			t.Error("input is still invalid after removing violations")
+		}
+	}
+}
+
+func TestForEachInvalidString(t *testing.T) {
+	s := "a\x00b\xed\xba\xad\u0085c\U0010FFFF"
+	var want []Violation
+	vs := Assignables.ScanUtf8([]byte(s))
+	for vs.Next() {
+		want = append(want, vs.Violation())
+	}
+	var got []Violation
+	Assignables.ForEachInvalidString(s, func(v Violation) bool {
+		got = append(got, v)
+		return true
+	})
+	if len(got) != len(want) {
+		t.Fatalf("ForEachInvalidString found %v, want %v", got, want)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Errorf("violation %d = %v, want %v", i, got[i], want[i])
+		}
+	}
+
+	// stopping early
+	count := 0
+	Assignables.ForEachInvalidString(s, func(v Violation) bool {
+		count++
+		return count < 2
+	})
+	if count != 2 {
+		t.Errorf("ForEachInvalidString called f %d times after being told to stop at 2", count)
+	}
+
+	Assignables.ForEachInvalidString("clean", func(v Violation) bool {
+		t.Error("called f on clean input")
+		return true
+	})
+}
+
+func TestForEachInvalidUtf8(t *testing.T) {
+	u := []byte("a\x00b\xed\xba\xad\u0085c\U0010FFFF")
+	want := Assignables.AllInvalidUtf8(u)
+	var got []Violation
+	Assignables.ForEachInvalidUtf8(u, func(v Violation) bool {
+		got = append(got, v)
+		return true
+	})
+	if !slices.Equal(got, want) {
+		t.Errorf("ForEachInvalidUtf8 found %v, want %v", got, want)
+	}
+
+	count := 0
+	Assignables.ForEachInvalidUtf8(u, func(v Violation) bool {
+		count++
+		return false
+	})
+	if count != 1 {
+		t.Errorf("ForEachInvalidUtf8 called f %d times after being told to stop at 1", count)
+	}
+
+	allocs := testing.AllocsPerRun(10, func() {
+		Assignables.ForEachInvalidUtf8(u, func(v Violation) bool {
+			count++
+			return true
+		})
+	})
+	if allocs != 0 {
+		t.Errorf("ForEachInvalidUtf8 allocated %v times", allocs)
+	}
+}
+
+func TestForEachSegment(t *testing.T) {
+	tests := []struct {
+		in   string
+		want []Segment
+	}{
+		{"", nil},
+		{"clean", []Segment{{0, 5, true}}},
+		{"\x00\xff", []Segment{{0, 2, false}}},
+		{"ab\x00\xff\uFFFEcd\x01", []Segment{{0, 2, true}, {2, 7, false}, {7, 9, true}, {9, 10, false}}},
+		{"\x01\u00e9", []Segment{{0, 1, false}, {1, 3, true}}},
+	}
+	for _, test := range tests {
+		var got []Segment
+		XmlChars.ForEachSegment(test.in, func(seg Segment) bool {
+			got = append(got, seg)
+			return true
+		})
+		if !slices.Equal(got, test.want) {
+			t.Errorf("ForEachSegment(%q) = %v, want %v", test.in, got, test.want)
+		}
+	}
+
+	calls := 0
+	XmlChars.ForEachSegment("a\x00b\x00", func(Segment) bool {
+		calls++
+		return false
+	})
+	if calls != 1 {
+		t.Errorf("ForEachSegment kept going after f returned false, %d calls", calls)
+	}
+}
+
+func TestAllInvalid(t *testing.T) {
+	in := "\x01ok\uFFFE\xff ok\x02"
+	want := []Violation{
+		{Offset: 0, Index: 0, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl},
+		{Offset: 3, Index: 3, Width: 3, Rune: 0xFFFE, Subset: XmlChars, Problem: Noncharacter},
+		{Offset: 6, Index: 4, Width: 1, Rune: utf8.RuneError, Subset: XmlChars, Problem: InvalidUtf8},
+		{Offset: 10, Index: 8, Width: 1, Rune: 2, Subset: XmlChars, Problem: LegacyControl},
+	}
+	if got := XmlChars.AllInvalidString(in); !slices.Equal(got, want) {
+		t.Errorf("AllInvalidString = %v, want %v", got, want)
+	}
+	if got := XmlChars.AllInvalidUtf8([]byte(in)); !slices.Equal(got, want) {
+		t.Errorf("AllInvalidUtf8 = %v, want %v", got, want)
+	}
+	if got := XmlChars.AllInvalidString("clean"); got != nil {
+		t.Errorf("AllInvalidString = %v, want nil", got)
+	}
+}
+
+func TestFirstInvalidIndexed(t *testing.T) {
+	// a stream with offsets in the caller's coordinates, here starting at 1000
+	stream := func(s string) func(yield func(int, rune) bool) {
+		return func(yield func(int, rune) bool) {
+			for offset, r := range s {
+				if !yield(1000+offset, r) {
+					return
+				}
+			}
+		}
+	}
+	v := XmlChars.FirstInvalidIndexed(stream("abé\x01z\x02"))
+	want := Violation{Offset: 1004, Index: 3, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl}
+	if v == nil || *v != want {
+		t.Errorf("FirstInvalidIndexed = %v, want %v", v, want)
+	}
+	if v := XmlChars.FirstInvalidIndexed(stream("all fine")); v != nil {
+		t.Errorf("FirstInvalidIndexed = %v, want nil", v)
+	}
+
+	// runes which can't be UTF-8 encoded
+	surrogate := func(yield func(int, rune) bool) {
+		yield(7, 0xD800)
+	}
+	v = Scalars.FirstInvalidIndexed(surrogate)
+	if v == nil || v.Offset != 7 || v.Width != 0 || v.Problem != Surrogate {
+		t.Errorf("FirstInvalidIndexed = %v", v)
+	}
+}
+
+func TestFirstInvalidRunes(t *testing.T) {
+	runes := func(rs ...rune) func(yield func(rune) bool) {
+		return func(yield func(rune) bool) {
+			for _, r := range rs {
+				if !yield(r) {
+					return
+				}
+			}
+		}
+	}
+	if !XmlChars.ValidRunes(runes()) || !XmlChars.ValidRunes(runes('a', 0xE9, 0x1F600)) {
+		t.Error("ValidRunes rejected valid runes")
+	}
+	if XmlChars.ValidRunes(runes('a', 0)) || Scalars.ValidRunes(runes(0xD800)) || Scalars.ValidRunes(runes(0x110000)) {
+		t.Error("ValidRunes accepted invalid runes")
+	}
+	v := XmlChars.FirstInvalidRunes(runes('a', 0xE9, 0x1F600, 0xFFFE, 1))
+	want := Violation{Offset: 7, Index: 3, Width: 3, Rune: 0xFFFE, Subset: XmlChars, Problem: Noncharacter}
+	if v == nil || *v != want {
+		t.Errorf("FirstInvalidRunes = %v, want %v", v, &want)
+	}
+}
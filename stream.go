@@ -0,0 +1,67 @@
+package rfc9839
+
+import (
+	"unicode/utf8"
+)
+
+// A StreamValidator checks input which arrives in arbitrary chunks, such as reads from the
+// network, without the caller having to buffer it. Unlike PartialValidator, it holds on to
+// the bytes of a rune which is split between chunks itself. A StreamValidator is not safe
+// for concurrent use.
+type StreamValidator struct {
+	pv        PartialValidator
+	pending   [utf8.UTFMax]byte
+	npending  int
+	violation *Violation
+}
+
+// NewStreamValidator returns a StreamValidator for sub.
+func NewStreamValidator(sub *Subset) *StreamValidator {
+	return &StreamValidator{pv: PartialValidator{sub: sub}}
+}
+
+// Feed checks the next chunk of input. It returns nil, or a *Violation describing the first
+// problem in the input so far, with its Offset counted from the start of the input. Once it
+// has found a Violation, it returns the same one for every subsequent call.
+func (sv *StreamValidator) Feed(chunk []byte) error {
+	if sv.violation != nil {
+		return sv.violation
+	}
+	if sv.npending > 0 {
+		// complete the rune left over from the previous chunk, one byte at a time
+		for len(chunk) > 0 && !utf8.FullRune(sv.pending[:sv.npending]) {
+			sv.pending[sv.npending] = chunk[0]
+			sv.npending++
+			chunk = chunk[1:]
+		}
+		if !utf8.FullRune(sv.pending[:sv.npending]) {
+			return nil
+		}
+		progress := sv.pv.Feed(sv.pending[:sv.npending])
+		sv.npending = 0
+		if progress.Violation != nil {
+			sv.violation = progress.Violation
+			return sv.violation
+		}
+	}
+	progress := sv.pv.Feed(chunk)
+	if progress.Violation != nil {
+		sv.violation = progress.Violation
+		return sv.violation
+	}
+	sv.npending = copy(sv.pending[:], chunk[progress.Consumed:])
+	return nil
+}
+
+// Finish is called at the end of the input. It returns nil, or a *Violation describing the
+// first problem in the input, including a rune which was cut short by the end of the input.
+func (sv *StreamValidator) Finish() error {
+	if sv.violation == nil && sv.npending > 0 {
+		sv.violation = sv.pv.Finish(sv.pending[:sv.npending])
+		sv.npending = 0
+	}
+	if sv.violation != nil {
+		return sv.violation
+	}
+	return nil
+}
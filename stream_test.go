@@ -0,0 +1,51 @@
+package rfc9839
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestStreamValidator(t *testing.T) {
+	inputs := []string{
+		"",
+		"plain ASCII",
+		"café 中\U0001F600 and more",
+		"split \U0001F600\U0001FFFF here",
+		"bad\xffbyte",
+		"surrogate \xed\xba\xad",
+		"truncated \xf0\x9f\x98",
+		"control\x01",
+	}
+	for _, in := range inputs {
+		want := Assignables.FirstInvalidString(in)
+		// every chunk size, so that runes are split at every possible point
+		for size := 1; size <= len(in)+1; size++ {
+			sv := NewStreamValidator(Assignables)
+			var err error
+			for i := 0; i < len(in) && err == nil; i += size {
+				err = sv.Feed([]byte(in[i:min(i+size, len(in))]))
+			}
+			if err == nil {
+				err = sv.Finish()
+			}
+			var got *Violation
+			if errors.As(err, &got) != (want != nil) || (want != nil && *got != *want) {
+				t.Errorf("%q in chunks of %d = %v, want %v", in, size, err, want)
+			}
+		}
+	}
+}
+
+func TestStreamValidatorSticky(t *testing.T) {
+	sv := NewStreamValidator(XmlChars)
+	first := sv.Feed([]byte("a\x00b"))
+	if first == nil {
+		t.Fatal("Feed missed the violation")
+	}
+	if err := sv.Feed([]byte("fine")); err != first {
+		t.Errorf("Feed after a violation = %v, want %v", err, first)
+	}
+	if err := sv.Finish(); err != first {
+		t.Errorf("Finish after a violation = %v, want %v", err, first)
+	}
+}
@@ -0,0 +1,117 @@
+package rfc9839
+
+import (
+	"errors"
+	"fmt"
+	"reflect"
+	"sort"
+)
+
+// A FieldViolation reports a string, somewhere in a Go value checked by CheckStruct, which is
+// not valid for a Subset.
+type FieldViolation struct {
+	Field string // path to the offending string, like "Home.Tags[1]" or `Notes["key"]`
+	Violation
+}
+
+func (fv *FieldViolation) Error() string {
+	return fmt.Sprintf("%s, in field %s", fv.Violation.Error(), fv.Field)
+}
+
+// Unwrap returns the underlying Violation, whose offsets are relative to the offending string.
+func (fv *FieldViolation) Unwrap() error {
+	return &fv.Violation
+}
+
+// ErrCycle is wrapped by the error CheckStruct returns for a value which refers to itself.
+var ErrCycle = errors.New("rfc9839: cycle in value")
+
+// CheckStruct walks v, which is typically a struct or a pointer to one, and checks every
+// string it reaches against the subset: exported string fields, and the strings in pointers,
+// interfaces, slices, arrays, nested structs, and the keys and values of maps, whose entries
+// are visited in sorted order so that the result is deterministic. It returns nil or a
+// *FieldViolation for the first offending string. Unexported fields are skipped. If v
+// refers to itself through a pointer, map, or slice, it returns an error wrapping ErrCycle.
+func (sub *Subset) CheckStruct(v any) error {
+	c := structChecker{sub: sub, seen: make(map[seenKey]bool)}
+	return c.check(reflect.ValueOf(v), "")
+}
+
+type structChecker struct {
+	sub  *Subset
+	seen map[seenKey]bool // the pointers, maps, and slices on the path being checked
+}
+
+// seenKey identifies a pointer, map, or slice; like encoding/json, it includes a slice's
+// length, since slices of different lengths can share a first element.
+type seenKey struct {
+	typ reflect.Type
+	ptr uintptr
+	len int
+}
+
+func (c *structChecker) check(rv reflect.Value, path string) error {
+	switch rv.Kind() {
+	case reflect.Pointer, reflect.Map, reflect.Slice:
+		if rv.IsNil() {
+			return nil
+		}
+		key := seenKey{typ: rv.Type(), ptr: rv.Pointer()}
+		if rv.Kind() == reflect.Slice {
+			key.len = rv.Len()
+		}
+		if c.seen[key] {
+			return fmt.Errorf("%w at field %s", ErrCycle, path)
+		}
+		c.seen[key] = true
+		defer delete(c.seen, key)
+	}
+
+	switch rv.Kind() {
+	case reflect.String:
+		if v := c.sub.FirstInvalidString(rv.String()); v != nil {
+			return &FieldViolation{Field: path, Violation: *v}
+		}
+	case reflect.Pointer, reflect.Interface:
+		if !rv.IsNil() {
+			return c.check(rv.Elem(), path)
+		}
+	case reflect.Struct:
+		for i := 0; i < rv.NumField(); i++ {
+			field := rv.Type().Field(i)
+			if !field.IsExported() {
+				continue
+			}
+			fieldPath := field.Name
+			if path != "" {
+				fieldPath = path + "." + field.Name
+			}
+			if err := c.check(rv.Field(i), fieldPath); err != nil {
+				return err
+			}
+		}
+	case reflect.Slice, reflect.Array:
+		for i := 0; i < rv.Len(); i++ {
+			if err := c.check(rv.Index(i), fmt.Sprintf("%s[%d]", path, i)); err != nil {
+				return err
+			}
+		}
+	case reflect.Map:
+		keys := rv.MapKeys()
+		sort.Slice(keys, func(i, j int) bool { return fmt.Sprint(keys[i]) < fmt.Sprint(keys[j]) })
+		for _, key := range keys {
+			// quoted, so that a key with odd characters in it shows them as escapes
+			keyPath := fmt.Sprintf("%s[%v]", path, key)
+			if key.Kind() == reflect.String {
+				keyPath = fmt.Sprintf("%s[%q]", path, key.String())
+			}
+			if err := c.check(key, keyPath); err != nil {
+				return err
+			}
+			if err := c.check(rv.MapIndex(key), keyPath); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
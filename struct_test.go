@@ -0,0 +1,87 @@
+package rfc9839
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestCheckStruct(t *testing.T) {
+	type address struct {
+		Street string
+		Tags   []string
+	}
+	type person struct {
+		Name     string
+		Nickname *string
+		Home     address
+		Work     *address
+		Notes    map[string]string
+		Extra    any
+		Age      int
+		secret   string
+	}
+	for _, in := range []any{nil, 42, person{Name: "Tim", Home: address{Tags: []string{"é"}}, secret: "\x00"}} {
+		if err := Assignables.CheckStruct(in); err != nil {
+			t.Errorf("CheckStruct(%+v) = %v", in, err)
+		}
+	}
+
+	bell := "ding\a"
+	tests := []struct {
+		in    any
+		field string
+		r     rune
+	}{
+		{"\x00", "", 0},
+		{&person{Name: "T\x00m"}, "Name", 0},
+		{person{Nickname: &bell}, "Nickname", 7},
+		{person{Home: address{Tags: []string{"ok", "\x01"}}}, "Home.Tags[1]", 1},
+		{person{Work: &address{Street: "\x7f"}}, "Work.Street", 0x7F},
+		{person{Notes: map[string]string{"b": "\x02", "a": "\x03"}}, `Notes["a"]`, 3},
+		{person{Notes: map[string]string{"\x04": "fine"}}, `Notes["\x04"]`, 4},
+		{person{Extra: []any{1, "\x05"}}, "Extra[1]", 5},
+	}
+	for _, test := range tests {
+		err := Assignables.CheckStruct(test.in)
+		var fv *FieldViolation
+		if !errors.As(err, &fv) || fv.Field != test.field || fv.Rune != test.r {
+			t.Errorf("CheckStruct(%+v) = %v, want a violation in %q", test.in, err, test.field)
+		}
+	}
+
+	err := Assignables.CheckStruct(person{Home: address{Street: "a\x00"}})
+	if got, want := err.Error(), "rfc9839: U+0000 (legacy control) at byte offset 1 is not in Assignables, in field Home.Street"; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+	var v *Violation
+	if !errors.As(err, &v) || v.Offset != 1 {
+		t.Errorf("CheckStruct didn't wrap the Violation: %v", err)
+	}
+}
+
+func TestCheckStructCycle(t *testing.T) {
+	type node struct {
+		Name string
+		Next *node
+	}
+	loop := &node{Name: "a"}
+	loop.Next = &node{Name: "b", Next: loop}
+	self := map[string]any{"name": "c"}
+	self["self"] = self
+	list := []any{"d", nil}
+	list[1] = list
+	for _, in := range []any{loop, self, list} {
+		if err := Assignables.CheckStruct(in); !errors.Is(err, ErrCycle) {
+			t.Errorf("CheckStruct of a cycle = %v", err)
+		}
+	}
+
+	// a value reached twice without a cycle is fine
+	shared := &node{Name: "e"}
+	if err := Assignables.CheckStruct([]*node{shared, shared}); err != nil {
+		t.Errorf("CheckStruct of a shared pointer = %v", err)
+	}
+	if err := Assignables.CheckStruct(&node{Name: "f", Next: loop}); err == nil || err.Error() != "rfc9839: cycle in value at field Next.Next.Next" {
+		t.Errorf("CheckStruct of a cycle = %v", err)
+	}
+}
@@ -0,0 +1,37 @@
+package rfc9839
+
+import (
+	"errors"
+)
+
+// ErrUnbalancedDelimiters is returned by CheckTemplate when placeholder delimiters don't pair up.
+var ErrUnbalancedDelimiters = errors.New("rfc9839: unbalanced template delimiters")
+
+// CheckTemplate checks s against the subset, except that the bytes between each left
+// delimiter and its matching right delimiter are skipped; placeholders may nest. The
+// delimiters themselves are checked. It returns nil if s is valid, a *Violation describing
+// the first problem, or ErrUnbalancedDelimiters.
+func (sub *Subset) CheckTemplate(s string, left, right rune) error {
+	depth := 0
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if depth == 0 && !ok {
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		switch {
+		case r == right && depth > 0:
+			depth--
+		case r == left:
+			depth++
+		case r == right:
+			return ErrUnbalancedDelimiters
+		}
+		offset += width
+	}
+	if depth != 0 {
+		return ErrUnbalancedDelimiters
+	}
+	return nil
+}
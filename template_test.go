@@ -0,0 +1,40 @@
+package rfc9839
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestCheckTemplate(t *testing.T) {
+	tests := []struct {
+		in    string
+		left  rune
+		right rune
+		want  error
+	}{
+		{"", '{', '}', nil},
+		{"hello {0}, you owe {1}", '{', '}', nil},
+		{"hello {\x00\xff}", '{', '}', nil},
+		{"hello {{\x00} nested \x01}", '{', '}', nil},
+		{"%\x00% and %\x01%", '%', '%', nil},
+		{"hello\x00 {0}", '{', '}', &Violation{Offset: 5, Index: 5, Width: 1, Rune: 0, Subset: XmlChars, Problem: LegacyControl}},
+		{"{0} \xff", '{', '}', &Violation{Offset: 4, Index: 4, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+		{"{0}\uFFFE", '{', '}', &Violation{Offset: 3, Index: 3, Width: 3, Rune: 0xFFFE, Subset: XmlChars, Problem: Noncharacter}},
+		{"{0", '{', '}', ErrUnbalancedDelimiters},
+		{"0}", '{', '}', ErrUnbalancedDelimiters},
+		{"{0}}\x00", '{', '}', ErrUnbalancedDelimiters},
+		{"%0", '%', '%', ErrUnbalancedDelimiters},
+	}
+	for _, test := range tests {
+		err := XmlChars.CheckTemplate(test.in, test.left, test.right)
+		var got, want *Violation
+		switch {
+		case errors.As(test.want, &want):
+			if !errors.As(err, &got) || *got != *want {
+				t.Errorf("CheckTemplate(%q) = %v, want %v", test.in, err, test.want)
+			}
+		case err != test.want:
+			t.Errorf("CheckTemplate(%q) = %v, want %v", test.in, err, test.want)
+		}
+	}
+}
@@ -1,6 +1,7 @@
 package rfc9839
 
 import (
+	"slices"
 	"unicode/utf8"
 )
 
@@ -9,18 +10,71 @@ type runePair struct {
 	hi rune
 }
 
-type Subset struct{ pairs []runePair }
+type Subset struct {
+	pairs []runePair
+	name  string
+	code  string
+	bmp   []uint64 // bitmap of the BMP, only with the rfc9839_bitmap build tag
+	ascii bool     // contains all of printable ASCII, so the fast path in ValidUtf8 applies
+}
+
+// String returns the subset's name, as used in error messages.
+func (sub *Subset) String() string {
+	return sub.name
+}
+
+// Code returns a short identifier for the subset, such as "XML", for use in logs and wire
+// formats. Unlike String, it is guaranteed never to change. Subsets other than the built-in
+// ones have an empty code.
+func (sub *Subset) Code() string {
+	return sub.code
+}
+
+// SubsetFromCode returns the built-in subset whose Code is code, or nil if there is none.
+func SubsetFromCode(code string) *Subset {
+	for _, sub := range builtinSubsets {
+		if sub.code == code {
+			return sub
+		}
+	}
+	return nil
+}
 
+// ValidRune reports whether r is in the subset.
 func (sub *Subset) ValidRune(r rune) bool {
 	return subsetContains(sub, r)
 }
+
+// ValidString reports whether s is correctly encoded UTF-8 and all its runes are in the
+// subset. The empty string is valid.
 func (sub *Subset) ValidString(s string) bool {
-	return isUTF8InSubset([]byte(s), sub)
+	return indexInvalidString(s, sub) < 0
 }
+
+// ValidUtf8 reports whether u is correctly encoded UTF-8 and all its runes are in the
+// subset. A nil or empty slice is valid, so there's no need to check for nil first.
 func (sub *Subset) ValidUtf8(u []byte) bool {
 	return isUTF8InSubset(u, sub)
 }
 
+// ValidStringIgnoring is like ValidString, but treats s as though the runes in ignore had
+// been removed from it first, whether or not they are in the subset. It doesn't build the
+// stripped copy. Invalid UTF-8 is never ignored.
+func (sub *Subset) ValidStringIgnoring(s string, ignore []rune) bool {
+	index := 0
+	for index < len(s) {
+		r, width, ok := decodeStringInSubset(s[index:], sub)
+		if !ok {
+			badUtf8 := r == utf8.RuneError && width == 1
+			if badUtf8 || !slices.Contains(ignore, r) {
+				return false
+			}
+		}
+		index += width
+	}
+	return true
+}
+
 // implementation note: the Subset could contain, instead of []runePair, a
 // unicode.RangeTable, then subsetContains could be replaced by unicode.Is(). We
 // implemented this, but it had a >2x performance penalty.
@@ -29,13 +83,17 @@ func (sub *Subset) ValidUtf8(u []byte) bool {
 
 // note that these are not sorted by numeric order, but by in descending order of
 // estimated traffic, as measured by Tim's guesswork. The idea is that you'd like
-// to minimize the number of PairContains calls.
+// to minimize the number of PairContains calls. Building with the rfc9839_sorted tag
+// replaces this linear scan with a binary search, and the rfc9839_bitmap tag with a bitmap
+// lookup; see lookup_sorted.go and lookup_bitmap.go.
 
 var Scalars = &Subset{
 	pairs: []runePair{
 		{0, 0xD7FF},        // most of the BMP
 		{0xE000, 0x10FFFF}, // mostly astral planes
 	},
+	name: "Scalars",
+	code: "SCALAR",
 }
 
 var XmlChars = &Subset{
@@ -47,6 +105,8 @@ var XmlChars = &Subset{
 		{0xD, 0xD},          // CR
 		{0x10000, 0x10FFFF}, // astral planes
 	},
+	name: "XmlChars",
+	code: "XML",
 }
 
 var Assignables = &Subset{
@@ -75,15 +135,137 @@ var Assignables = &Subset{
 		{0xF0000, 0xFFFFD},
 		{0x100000, 0x10FFFD},
 	},
+	name: "Assignables",
+	code: "ASSIGN",
 }
 
-func pairContains(pair runePair, r rune) bool {
-	return r >= pair.lo && r <= pair.hi
+// XmlCharsConservative is XmlChars without U+0085 (NEL) and U+2028 and U+2029 (the line and
+// paragraph separators), which XML permits but which trip up many consumers of XML.
+var XmlCharsConservative = &Subset{
+	pairs: []runePair{
+		{0x20, 0x84},        // ASCII and the start of Latin-1
+		{0xA, 0xA},          // newline
+		{0x86, 0x2027},      // BMP up to the line and paragraph separators
+		{0x202A, 0xD7FF},    // rest of the BMP before surrogates
+		{0xE000, 0xFFFD},    // BMP after surrogates
+		{0x9, 0x9},          // Tab
+		{0xD, 0xD},          // CR
+		{0x10000, 0x10FFFF}, // astral planes
+	},
+	name: "XmlCharsConservative",
+	code: "XML-CONSERVATIVE",
 }
 
-func subsetContains(sub *Subset, r rune) bool {
-	for _, pair := range sub.pairs {
-		if pairContains(pair, r) {
+// Xml11Chars is the Char production of XML 1.1, which unlike XML 1.0 allows every control
+// except NUL. Note that XML 1.1 requires most controls to appear as character references
+// rather than literally.
+var Xml11Chars = &Subset{
+	pairs: []runePair{
+		{0x1, 0xD7FF},       // all but NUL of the BMP before surrogates
+		{0xE000, 0xFFFD},    // BMP after surrogates
+		{0x10000, 0x10FFFF}, // astral planes
+	},
+	name: "Xml11Chars",
+	code: "XML11",
+}
+
+// Xml11LiteralChars is the characters which XML 1.1 allows to appear literally: Xml11Chars
+// without the RestrictedChar production, which is the controls other than tab, newline,
+// carriage return, and U+0085 (NEL). The restricted characters may appear only as character
+// references such as &#x1;.
+var Xml11LiteralChars = &Subset{
+	pairs: []runePair{
+		{0x20, 0x7E},        // ASCII
+		{0xA, 0xA},          // newline
+		{0xA0, 0xD7FF},      // BMP after the C1 controls
+		{0xE000, 0xFFFD},    // BMP after surrogates
+		{0x9, 0x9},          // Tab
+		{0xD, 0xD},          // CR
+		{0x85, 0x85},        // NEL, which XML 1.1 treats as a line end
+		{0x10000, 0x10FFFF}, // astral planes
+	},
+	name: "Xml11LiteralChars",
+	code: "XML11-LITERAL",
+}
+
+// AssignablesNoCR is Assignables without U+000D (CR), for protocols which normalize line
+// ends to LF and want to reject a raw CR as early as possible, leaving tab and newline as the
+// only controls. Other variants can be made with SubsetBuilder.
+var AssignablesNoCR = &Subset{
+	pairs: []runePair{
+		{0x20, 0x7E},       // ASCII
+		{0xA, 0xA},         // newline
+		{0xA0, 0xD7FF},     // most of the BMP
+		{0xE000, 0xFDCF},   // BMP after surrogates
+		{0xFDF0, 0xFFFD},   // BMP after noncharacters block
+		{0x9, 0x9},         // Tab
+		{0x10000, 0x1FFFD}, // astral planes from here down
+		{0x20000, 0x2FFFD},
+		{0x30000, 0x3FFFD},
+		{0x40000, 0x4FFFD},
+		{0x50000, 0x5FFFD},
+		{0x60000, 0x6FFFD},
+		{0x70000, 0x7FFFD},
+		{0x80000, 0x8FFFD},
+		{0x90000, 0x9FFFD},
+		{0xA0000, 0xAFFFD},
+		{0xB0000, 0xBFFFD},
+		{0xC0000, 0xCFFFD},
+		{0xD0000, 0xDFFFD},
+		{0xE0000, 0xEFFFD},
+		{0xF0000, 0xFFFFD},
+		{0x100000, 0x10FFFD},
+	},
+	name: "AssignablesNoCR",
+	code: "ASSIGN-NOCR",
+}
+
+// AssignablesNoPrivateUse is Assignables without the private-use code points, U+E000 through
+// U+F8FF and planes 15 and 16, whose meaning is only by private agreement and which registries
+// for interchange often forbid.
+var AssignablesNoPrivateUse = &Subset{
+	pairs: []runePair{
+		{0x20, 0x7E},       // ASCII
+		{0xA, 0xA},         // newline
+		{0xA0, 0xD7FF},     // most of the BMP
+		{0xF900, 0xFDCF},   // BMP after the private use area
+		{0xFDF0, 0xFFFD},   // BMP after noncharacters block
+		{0x9, 0x9},         // Tab
+		{0xD, 0xD},         // CR
+		{0x10000, 0x1FFFD}, // astral planes from here down, but not the private-use planes
+		{0x20000, 0x2FFFD},
+		{0x30000, 0x3FFFD},
+		{0x40000, 0x4FFFD},
+		{0x50000, 0x5FFFD},
+		{0x60000, 0x6FFFD},
+		{0x70000, 0x7FFFD},
+		{0x80000, 0x8FFFD},
+		{0x90000, 0x9FFFD},
+		{0xA0000, 0xAFFFD},
+		{0xB0000, 0xBFFFD},
+		{0xC0000, 0xCFFFD},
+		{0xD0000, 0xDFFFD},
+		{0xE0000, 0xEFFFD},
+	},
+	name: "AssignablesNoPrivateUse",
+	code: "ASSIGN-NOPUA",
+}
+
+var builtinSubsets = []*Subset{
+	Scalars, XmlChars, Assignables, XmlCharsConservative, Xml11Chars, Xml11LiteralChars, AssignablesNoCR,
+	AssignablesNoPrivateUse,
+}
+
+func init() {
+	for _, sub := range builtinSubsets {
+		sub.ascii = containsPrintableAscii(sub.pairs)
+	}
+}
+
+// containsPrintableAscii reports whether a single one of pairs covers U+0020 through U+007E.
+func containsPrintableAscii(pairs []runePair) bool {
+	for _, pair := range pairs {
+		if pair.lo <= 0x20 && pair.hi >= 0x7E {
 			return true
 		}
 	}
@@ -93,6 +275,19 @@ func subsetContains(sub *Subset, r rune) bool {
 func isUTF8InSubset(u []byte, sub *Subset) bool {
 	index := 0
 	for index < len(u) {
+		if u[index] < utf8.RuneSelf {
+			// ASCII needs no decoding, and the call to DecodeRune is significant on the
+			// common all-valid path. Runs of printable ASCII are checked 8 bytes at a time.
+			if sub.ascii && index+8 <= len(u) && printableAscii8(u[index:]) {
+				index += 8
+				continue
+			}
+			if !subsetContains(sub, rune(u[index])) {
+				return false
+			}
+			index++
+			continue
+		}
 		r, width := utf8.DecodeRune(u[index:])
 		if r == 0xFFFD && width == 1 {
 			// this is how the utf8 pkg signals invalid UTF8 bytes, notably
@@ -106,3 +301,44 @@ func isUTF8InSubset(u []byte, sub *Subset) bool {
 	}
 	return true
 }
+
+// decodeStringInSubset decodes the first rune in s, which must not be empty, and reports
+// whether it is both correctly encoded and in the subset.
+func decodeStringInSubset(s string, sub *Subset) (r rune, width int, ok bool) {
+	if s[0] < utf8.RuneSelf {
+		r = rune(s[0])
+		return r, 1, subsetContains(sub, r)
+	}
+	r, width = utf8.DecodeRuneInString(s)
+	if r == utf8.RuneError && width == 1 {
+		return r, width, false
+	}
+	return r, width, subsetContains(sub, r)
+}
+
+// indexInvalidString returns the byte offset of the first rune in s which is not in the
+// subset or is not correctly encoded, or -1 if there is none.
+func indexInvalidString(s string, sub *Subset) int {
+	index := 0
+	for index < len(s) {
+		_, width, ok := decodeStringInSubset(s[index:], sub)
+		if !ok {
+			return index
+		}
+		index += width
+	}
+	return -1
+}
+
+// decodeInSubset is the []byte equivalent of decodeStringInSubset.
+func decodeInSubset(u []byte, sub *Subset) (r rune, width int, ok bool) {
+	if u[0] < utf8.RuneSelf {
+		r = rune(u[0])
+		return r, 1, subsetContains(sub, r)
+	}
+	r, width = utf8.DecodeRune(u)
+	if r == utf8.RuneError && width == 1 {
+		return r, width, false
+	}
+	return r, width, subsetContains(sub, r)
+}
@@ -2,6 +2,7 @@ package rfc9839
 
 import (
 	"os"
+	"strings"
 	"testing"
 )
 
@@ -43,3 +44,40 @@ func BenchmarkValidString(b *testing.B) {
 		XmlChars.ValidString(s)
 	}
 }
+
+type benchCorpus struct {
+	name string
+	text []byte
+}
+
+// corpora for comparing lookup layouts; run the benchmarks with no tags, then with
+// -tags rfc9839_sorted and -tags rfc9839_bitmap
+func benchCorpora(b *testing.B) []benchCorpus {
+	sample, err := os.ReadFile("testdata/sample.txt")
+	if err != nil {
+		b.Fatal(err)
+	}
+	return []benchCorpus{
+		{"ASCII", []byte(strings.Repeat("{\"id\": 42, \"name\": \"The quick brown fox\"}\n", 800))},
+		{"CJK", []byte(strings.Repeat("東京都は日本の首都である。北京是中国的首都。서울은 한국의 수도이다。", 400))},
+		{"Emoji", []byte(strings.Repeat("😀🎉🚀👍🏽🐈🌏🍣📈🧪🛰", 800))},
+		{"Mixed", sample},
+	}
+}
+
+func BenchmarkCorpora(b *testing.B) {
+	for _, corpus := range benchCorpora(b) {
+		for _, sub := range []*Subset{Scalars, XmlChars, Assignables} {
+			text := corpus.text
+			b.Run(corpus.name+"/"+sub.String(), func(b *testing.B) {
+				b.SetBytes(int64(len(text)))
+				b.ReportAllocs()
+				for i := 0; i < b.N; i++ {
+					if !sub.ValidUtf8(text) {
+						b.Fatal("corpus should be valid")
+					}
+				}
+			})
+		}
+	}
+}
@@ -3,6 +3,7 @@ package rfc9839
 import (
 	"os"
 	"testing"
+	"unicode"
 	"unicode/utf8"
 )
 
@@ -393,3 +394,214 @@ func TestInvalidSampleString(t *testing.T) {
 		}
 	})
 }
+
+func TestDelAndC1Boundaries(t *testing.T) {
+	// XML only excludes controls below U+0020, while Assignables also excludes DEL and the C1 controls
+	tests := []struct {
+		r          rune
+		assignable bool
+	}{
+		{0x7E, true},
+		{0x7F, false},
+		{0x80, false},
+		{0x85, false},
+		{0x9F, false},
+		{0xA0, true},
+	}
+	for _, test := range tests {
+		if Assignables.ValidRune(test.r) != test.assignable {
+			t.Errorf("Assignables.ValidRune(%x) should be %t", test.r, test.assignable)
+		}
+		if !XmlChars.ValidRune(test.r) {
+			t.Errorf("%x should be XML", test.r)
+		}
+		if !Scalars.ValidRune(test.r) {
+			t.Errorf("%x should be unicode scalar", test.r)
+		}
+		s := "a" + string(test.r) + "z"
+		if Assignables.ValidString(s) != test.assignable {
+			t.Errorf("Assignables.ValidString(%q) should be %t", s, test.assignable)
+		}
+		if !XmlChars.ValidString(s) {
+			t.Errorf("XmlChars.ValidString(%q) should be true", s)
+		}
+	}
+}
+
+func TestLookupMatchesTables(t *testing.T) {
+	// whichever lookup is compiled in, linear, rfc9839_sorted, or rfc9839_bitmap, must agree
+	// with the tables
+	for _, sub := range builtinSubsets {
+		for r := rune(-1); r <= 0x10FFFF+1; r++ {
+			inTable := false
+			for _, pair := range sub.pairs {
+				if r >= pair.lo && r <= pair.hi {
+					inTable = true
+					break
+				}
+			}
+			if sub.ValidRune(r) != inTable {
+				t.Fatalf("ValidRune(%x) disagrees with the table", r)
+			}
+		}
+	}
+}
+
+var inverseXmlConservative = []rune{
+	0x0000, 0x0008, // control characters
+	0x000B, 0x000C, // vertical tab, form feed
+	0x000E, 0x001F, // control characters
+	0x0085,         // NEL
+	0x2028, 0x2029, // line and paragraph separators
+	0xD800, 0xDFFF, // surrogate pairs
+	0xFFFE, 0xFFFF, // noncharacters
+}
+
+func TestXmlCharsConservative(t *testing.T) {
+	for _, pair := range XmlCharsConservative.pairs {
+		for r := pair.lo; r <= pair.hi; r += 1 {
+			if !XmlCharsConservative.ValidRune(r) {
+				t.Errorf("%x should be conservative XML", r)
+			}
+			if !XmlChars.ValidRune(r) {
+				t.Errorf("%x should be XML", r)
+			}
+		}
+	}
+	for _, r := range inverseXmlConservative {
+		if XmlCharsConservative.ValidRune(r) {
+			t.Errorf("%x should not be conservative XML", r)
+		}
+	}
+	if XmlCharsConservative.ValidRune(-1) || XmlCharsConservative.ValidRune(0x10FFFF+1) {
+		t.Error("out-of-range rune accepted")
+	}
+	for _, s := range []string{"a\u0085z", "a\u2028z", "a\u2029z"} {
+		if !XmlChars.ValidString(s) {
+			t.Errorf("%q should be XML", s)
+		}
+		if XmlCharsConservative.ValidString(s) {
+			t.Errorf("%q should not be conservative XML", s)
+		}
+	}
+	if !XmlCharsConservative.ValidString("a\u0084\u0086\u2027\u202Az\t\r\n") {
+		t.Error("good string rejected")
+	}
+}
+
+func TestXml11Chars(t *testing.T) {
+	for r := rune(-1); r <= 0x10FFFF+1; r++ {
+		want := (r >= 1 && r <= 0xD7FF) || (r >= 0xE000 && r <= 0xFFFD) || (r >= 0x10000 && r <= 0x10FFFF)
+		if Xml11Chars.ValidRune(r) != want {
+			t.Fatalf("Xml11Chars.ValidRune(%x) should be %t", r, want)
+		}
+		if XmlChars.ValidRune(r) && !Xml11Chars.ValidRune(r) {
+			t.Fatalf("%x is XML 1.0 but not XML 1.1", r)
+		}
+	}
+	if !Xml11Chars.ValidString("a\x01\x08\x0b\x1f\x7f\u0085b") {
+		t.Error("XML 1.1 controls rejected")
+	}
+	if Xml11Chars.ValidString("a\x00b") || Xml11Chars.ValidUtf8([]byte("a\xed\xba\xadb")) {
+		t.Error("NUL or surrogate accepted")
+	}
+}
+
+func TestXml11LiteralChars(t *testing.T) {
+	restricted := func(r rune) bool {
+		return (r >= 0x1 && r <= 0x8) || r == 0xB || r == 0xC || (r >= 0xE && r <= 0x1F) ||
+			(r >= 0x7F && r <= 0x84) || (r >= 0x86 && r <= 0x9F)
+	}
+	for r := rune(-1); r <= 0x10FFFF+1; r++ {
+		want := Xml11Chars.ValidRune(r) && !restricted(r)
+		if Xml11LiteralChars.ValidRune(r) != want {
+			t.Fatalf("Xml11LiteralChars.ValidRune(%x) should be %t", r, want)
+		}
+	}
+	if !Xml11LiteralChars.ValidString("a\t\r\n\u0085\u00A0\u2028b") {
+		t.Error("XML 1.1 literal characters rejected")
+	}
+	if Xml11LiteralChars.ValidString("a\x01b") || Xml11LiteralChars.ValidString("a\u0086b") {
+		t.Error("restricted character accepted")
+	}
+}
+
+func TestAssignablesNoCR(t *testing.T) {
+	for r := rune(-1); r <= 0x10FFFF+1; r++ {
+		want := Assignables.ValidRune(r) && r != '\r'
+		if AssignablesNoCR.ValidRune(r) != want {
+			t.Fatalf("AssignablesNoCR.ValidRune(%x) should be %t", r, want)
+		}
+	}
+	if !AssignablesNoCR.ValidString("line one\n\tline two\n") {
+		t.Error("LF-only text rejected")
+	}
+	if v := AssignablesNoCR.FirstInvalidString("line one\r\n"); v == nil || v.Offset != 8 {
+		t.Errorf("FirstInvalidString of CRLF = %v", v)
+	}
+}
+
+func TestAssignablesNoPrivateUse(t *testing.T) {
+	for r := rune(-1); r <= 0x10FFFF+1; r++ {
+		want := Assignables.ValidRune(r) && !unicode.Is(unicode.Co, r)
+		if AssignablesNoPrivateUse.ValidRune(r) != want {
+			t.Fatalf("AssignablesNoPrivateUse.ValidRune(%x) should be %t", r, want)
+		}
+	}
+	if !AssignablesNoPrivateUse.IsSubsetOf(Assignables) {
+		t.Error("AssignablesNoPrivateUse is not a subset of Assignables")
+	}
+}
+
+func TestSubsetCodes(t *testing.T) {
+	want := map[*Subset]string{
+		Scalars:                 "SCALAR",
+		XmlChars:                "XML",
+		Assignables:             "ASSIGN",
+		XmlCharsConservative:    "XML-CONSERVATIVE",
+		Xml11Chars:              "XML11",
+		Xml11LiteralChars:       "XML11-LITERAL",
+		AssignablesNoCR:         "ASSIGN-NOCR",
+		AssignablesNoPrivateUse: "ASSIGN-NOPUA",
+	}
+	for _, sub := range builtinSubsets {
+		if sub.Code() != want[sub] {
+			t.Errorf("%s.Code() = %q, want %q", sub, sub.Code(), want[sub])
+		}
+		if SubsetFromCode(sub.Code()) != sub {
+			t.Errorf("SubsetFromCode(%q) doesn't round-trip", sub.Code())
+		}
+	}
+	if len(want) != len(builtinSubsets) {
+		t.Error("builtinSubsets is incomplete")
+	}
+	for _, code := range []string{"", "xml", "XmlChars", "SCALARS"} {
+		if SubsetFromCode(code) != nil {
+			t.Errorf("SubsetFromCode(%q) should be nil", code)
+		}
+	}
+}
+
+func TestValidStringIgnoring(t *testing.T) {
+	soft := []rune{0xAD, 0x200D, 0x7F}
+	tests := []struct {
+		in   string
+		want bool
+	}{
+		{"", true},
+		{"plain", true},
+		{"soft\u00ADhy\u200Dphen", true},
+		{"del\x7f", true},
+		{"nul\x00", false},
+		{"bad\xff", false},
+		{"\uFFFD", true},
+	}
+	for _, test := range tests {
+		if got := Assignables.ValidStringIgnoring(test.in, soft); got != test.want {
+			t.Errorf("ValidStringIgnoring(%q) = %t, want %t", test.in, got, test.want)
+		}
+	}
+	if !Assignables.ValidStringIgnoring("", nil) || Assignables.ValidStringIgnoring("\x7f", nil) {
+		t.Error("ValidStringIgnoring with nothing ignored should match ValidString")
+	}
+}
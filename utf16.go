@@ -0,0 +1,64 @@
+package rfc9839
+
+import (
+	"fmt"
+)
+
+// A Utf16LimitError reports that some input, although valid, is longer than a limit counted
+// in UTF-16 code units, as JavaScript and Java count the length of strings.
+type Utf16LimitError struct {
+	Limit  int // the maximum number of UTF-16 code units allowed
+	Offset int // byte offset of the rune which pushed the length past the limit
+}
+
+func (e *Utf16LimitError) Error() string {
+	return fmt.Sprintf("rfc9839: longer than %d UTF-16 code units at byte offset %d", e.Limit, e.Offset)
+}
+
+// CheckStringUtf16Limit checks in one pass that s is valid for the subset and that its
+// UTF-16 encoding, in which runes outside the BMP take two code units, is no longer than
+// maxUnits. It returns nil, or whichever of a *Violation or a *Utf16LimitError comes first
+// in s.
+func (sub *Subset) CheckStringUtf16Limit(s string, maxUnits int) error {
+	units := 0
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		units++
+		if r >= 0x10000 {
+			// encoded as a surrogate pair
+			units++
+		}
+		if units > maxUnits {
+			return &Utf16LimitError{Limit: maxUnits, Offset: offset}
+		}
+		offset += width
+	}
+	return nil
+}
+
+// ValidUtf16 reports whether u is correctly encoded UTF-16, with no unpaired surrogates, and
+// all its runes are in the subset. This saves transcoding data from Windows or JavaScript.
+func (sub *Subset) ValidUtf16(u []uint16) bool {
+	for i := 0; i < len(u); i++ {
+		r := rune(u[i])
+		switch {
+		case r >= 0xD800 && r <= 0xDBFF:
+			if i+1 == len(u) || u[i+1] < 0xDC00 || u[i+1] > 0xDFFF {
+				return false
+			}
+			r = 0x10000 + (r-0xD800)<<10 + rune(u[i+1]) - 0xDC00
+			i++
+		case r >= 0xDC00 && r <= 0xDFFF:
+			return false
+		}
+		if !subsetContains(sub, r) {
+			return false
+		}
+	}
+	return true
+}
@@ -0,0 +1,77 @@
+package rfc9839
+
+import (
+	"errors"
+	"testing"
+	"unicode/utf16"
+)
+
+func TestCheckStringUtf16Limit(t *testing.T) {
+	tests := []struct {
+		in    string
+		limit int
+		want  error
+	}{
+		{"", 0, nil},
+		{"abc", 3, nil},
+		{"abc", 2, &Utf16LimitError{Limit: 2, Offset: 2}},
+		{"a\U0001F600", 3, nil},
+		{"a\U0001F600", 2, &Utf16LimitError{Limit: 2, Offset: 1}},
+		{"éé", 2, nil},
+		{"ab\x01cdef", 3, &Violation{Offset: 2, Index: 2, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl}},
+		{"abcdef\x01", 3, &Utf16LimitError{Limit: 3, Offset: 3}},
+		{"a\xff", 5, &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+	}
+	for _, test := range tests {
+		err := XmlChars.CheckStringUtf16Limit(test.in, test.limit)
+		var gotV, wantV *Violation
+		var gotL, wantL *Utf16LimitError
+		switch {
+		case errors.As(test.want, &wantV):
+			if !errors.As(err, &gotV) || *gotV != *wantV {
+				t.Errorf("CheckStringUtf16Limit(%q, %d) = %v, want %v", test.in, test.limit, err, test.want)
+			}
+		case errors.As(test.want, &wantL):
+			if !errors.As(err, &gotL) || *gotL != *wantL {
+				t.Errorf("CheckStringUtf16Limit(%q, %d) = %v, want %v", test.in, test.limit, err, test.want)
+			}
+		case err != nil:
+			t.Errorf("CheckStringUtf16Limit(%q, %d) = %v, want nil", test.in, test.limit, err)
+		}
+	}
+}
+
+func TestValidUtf16(t *testing.T) {
+	tests := []struct {
+		sub  *Subset
+		in   []uint16
+		want bool
+	}{
+		{XmlChars, nil, true},
+		{XmlChars, utf16.Encode([]rune("café\r\n\U0001F600\U0010FFFF")), true},
+		{Assignables, utf16.Encode([]rune("\U0010FFFF")), false},
+		{XmlChars, []uint16{'a', 0}, false},
+		{Scalars, []uint16{'a', 0}, true},
+		{Scalars, []uint16{0xD83D}, false},
+		{Scalars, []uint16{0xD83D, 'a'}, false},
+		{Scalars, []uint16{0xDE00, 0xD83D}, false},
+		{Scalars, []uint16{0xD83D, 0xDE00}, true},
+		{XmlChars, []uint16{0xFFFE}, false},
+		{Assignables, []uint16{0xD83F, 0xDFFE}, false},
+	}
+	for _, test := range tests {
+		if got := test.sub.ValidUtf16(test.in); got != test.want {
+			t.Errorf("%s.ValidUtf16(%X) = %t, want %t", test.sub, test.in, got, test.want)
+		}
+	}
+
+	// agreement with decoding every scalar
+	for r := rune(0); r <= 0x10FFFF; r++ {
+		if utf16.IsSurrogate(r) {
+			continue
+		}
+		if Assignables.ValidUtf16(utf16.Encode([]rune{r})) != Assignables.ValidRune(r) {
+			t.Fatalf("ValidUtf16 disagrees with ValidRune at %X", r)
+		}
+	}
+}
@@ -0,0 +1,22 @@
+package rfc9839
+
+// ValidUtf32 reports whether every code point in u, as produced by text pipelines which work
+// on arrays of code points, is in the subset. Surrogates and values beyond U+10FFFF are in
+// no subset, so they are always rejected.
+func (sub *Subset) ValidUtf32(u []uint32) bool {
+	return sub.FirstInvalidUtf32(u) == nil
+}
+
+// FirstInvalidUtf32 returns a Violation describing the first code point in u which is not in
+// the subset, or nil if there is none. Its Index is the code point's index in u, and its
+// Offset and Width count bytes, four to a code point. A value too large for a rune shows up
+// as a negative Rune, whose Problem, like that of any value beyond U+10FFFF, is OutOfRange.
+func (sub *Subset) FirstInvalidUtf32(u []uint32) *Violation {
+	for i, cp := range u {
+		if r := rune(cp); !subsetContains(sub, r) {
+			v := newViolation(sub, 4*i, i, 4, r)
+			return &v
+		}
+	}
+	return nil
+}
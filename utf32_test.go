@@ -0,0 +1,33 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestFirstInvalidUtf32(t *testing.T) {
+	tests := []struct {
+		in   []uint32
+		want *Violation
+	}{
+		{nil, nil},
+		{[]uint32{'a', 0xE9, 0x1F600, 0x10FFFD}, nil},
+		{[]uint32{'a', 0xD800}, &Violation{Offset: 4, Index: 1, Width: 4, Rune: 0xD800, Subset: Assignables, Problem: Surrogate}},
+		{[]uint32{'a', 'b', 0x110000}, &Violation{Offset: 8, Index: 2, Width: 4, Rune: 0x110000, Subset: Assignables, Problem: OutOfRange}},
+		{[]uint32{0xFFFFFFFF}, &Violation{Offset: 0, Index: 0, Width: 4, Rune: -1, Subset: Assignables, Problem: OutOfRange}},
+		{[]uint32{'\t', 0x7F}, &Violation{Offset: 4, Index: 1, Width: 4, Rune: 0x7F, Subset: Assignables, Problem: LegacyControl}},
+		{[]uint32{0xFDD0}, &Violation{Offset: 0, Index: 0, Width: 4, Rune: 0xFDD0, Subset: Assignables, Problem: Noncharacter}},
+	}
+	for _, test := range tests {
+		got := Assignables.FirstInvalidUtf32(test.in)
+		if (got == nil) != (test.want == nil) || (got != nil && *got != *test.want) {
+			t.Errorf("FirstInvalidUtf32(%X) = %v, want %v", test.in, got, test.want)
+		}
+		if Assignables.ValidUtf32(test.in) != (test.want == nil) {
+			t.Errorf("ValidUtf32(%X) = %t", test.in, !(test.want == nil))
+		}
+	}
+	// the values which Scalars rejects are exactly those which aren't scalars
+	if Scalars.ValidUtf32([]uint32{0xDFFF}) || !Scalars.ValidUtf32([]uint32{0, 0x10FFFF}) {
+		t.Error("ValidUtf32 disagrees with Scalars")
+	}
+}
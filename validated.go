@@ -0,0 +1,239 @@
+package rfc9839
+
+import (
+	"strings"
+	"unicode/utf8"
+)
+
+// An XmlString holds a string which is known to be in XmlChars, so that APIs can require
+// checked text in their signatures. The zero value is the empty string, which is valid. As a
+// field of a struct decoded by encoding/json, it is checked as it is decoded, and so are
+// *XmlString for an optional field and []XmlString for a repeated one.
+type XmlString struct {
+	s string
+}
+
+// NewXmlString returns s as an XmlString, or a *Violation if it is not valid for XmlChars.
+func NewXmlString(s string) (XmlString, error) {
+	if v := XmlChars.FirstInvalidString(s); v != nil {
+		return XmlString{}, v
+	}
+	return XmlString{s: s}, nil
+}
+
+// UncheckedXmlString returns s as an XmlString without checking it, for input which is
+// already known to be valid, such as data read back from a store which only accepts
+// XmlStrings. Passing it anything else breaks the guarantee which XmlString exists to give.
+func UncheckedXmlString(s string) XmlString {
+	return XmlString{s: s}
+}
+
+// String returns the underlying string.
+func (x XmlString) String() string {
+	return x.s
+}
+
+// MarshalText implements encoding.TextMarshaler, so that an XmlString is encoded as a plain
+// string by packages such as encoding/json.
+func (x XmlString) MarshalText() ([]byte, error) {
+	return []byte(x.s), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler, so that decoding text which is not valid
+// for XmlChars into an XmlString fails with a *Violation.
+func (x *XmlString) UnmarshalText(text []byte) error {
+	if v := XmlChars.FirstInvalidUtf8(text); v != nil {
+		return v
+	}
+	x.s = string(text)
+	return nil
+}
+
+// An AssignableString holds a string which is known to be in Assignables. The zero value is
+// the empty string, which is valid.
+type AssignableString struct {
+	s string
+}
+
+// NewAssignableString returns s as an AssignableString, or a *Violation if it is not valid
+// for Assignables.
+func NewAssignableString(s string) (AssignableString, error) {
+	if v := Assignables.FirstInvalidString(s); v != nil {
+		return AssignableString{}, v
+	}
+	return AssignableString{s: s}, nil
+}
+
+// UncheckedAssignableString is the equivalent of UncheckedXmlString for AssignableString.
+func UncheckedAssignableString(s string) AssignableString {
+	return AssignableString{s: s}
+}
+
+// String returns the underlying string.
+func (a AssignableString) String() string {
+	return a.s
+}
+
+// XmlString returns a as an XmlString, without checking it again, since Assignables is a
+// subset of XmlChars.
+func (a AssignableString) XmlString() XmlString {
+	return XmlString{s: a.s}
+}
+
+// MarshalText implements encoding.TextMarshaler.
+func (a AssignableString) MarshalText() ([]byte, error) {
+	return []byte(a.s), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler, failing with a *Violation for text
+// which is not valid for Assignables.
+func (a *AssignableString) UnmarshalText(text []byte) error {
+	if v := Assignables.FirstInvalidUtf8(text); v != nil {
+		return v
+	}
+	a.s = string(text)
+	return nil
+}
+
+// checkedBuilder is the machinery shared by the validated string builders.
+type checkedBuilder struct {
+	b     strings.Builder
+	runes int
+}
+
+func (cb *checkedBuilder) writeRune(sub *Subset, r rune) error {
+	if !subsetContains(sub, r) {
+		v := newViolation(sub, cb.b.Len(), cb.runes, max(utf8.RuneLen(r), 0), r)
+		return &v
+	}
+	cb.b.WriteRune(r)
+	cb.runes++
+	return nil
+}
+
+func (cb *checkedBuilder) writeString(sub *Subset, s string) error {
+	if v := sub.FirstInvalidString(s); v != nil {
+		v.Offset += cb.b.Len()
+		v.Index += cb.runes
+		return v
+	}
+	cb.b.WriteString(s)
+	cb.runes += utf8.RuneCountInString(s)
+	return nil
+}
+
+// An XmlStringBuilder builds an XmlString piece by piece, checking each piece as it is
+// written. A piece which is not valid is not written, and the error is a *Violation whose
+// Offset and Index count from the start of the string built so far. The zero value is ready
+// to use. Like strings.Builder, an XmlStringBuilder must not be copied after first use.
+type XmlStringBuilder struct {
+	cb checkedBuilder
+}
+
+// WriteRune appends r, if it is in XmlChars.
+func (xb *XmlStringBuilder) WriteRune(r rune) error {
+	return xb.cb.writeRune(XmlChars, r)
+}
+
+// WriteString appends s, if it is valid for XmlChars.
+func (xb *XmlStringBuilder) WriteString(s string) error {
+	return xb.cb.writeString(XmlChars, s)
+}
+
+// XmlString returns what has been built.
+func (xb *XmlStringBuilder) XmlString() XmlString {
+	return XmlString{s: xb.cb.b.String()}
+}
+
+// An AssignableStringBuilder is the equivalent of XmlStringBuilder for AssignableString.
+type AssignableStringBuilder struct {
+	cb checkedBuilder
+}
+
+// WriteRune appends r, if it is in Assignables.
+func (ab *AssignableStringBuilder) WriteRune(r rune) error {
+	return ab.cb.writeRune(Assignables, r)
+}
+
+// WriteString appends s, if it is valid for Assignables.
+func (ab *AssignableStringBuilder) WriteString(s string) error {
+	return ab.cb.writeString(Assignables, s)
+}
+
+// AssignableString returns what has been built.
+func (ab *AssignableStringBuilder) AssignableString() AssignableString {
+	return AssignableString{s: ab.cb.b.String()}
+}
+
+// A SubsetDef names a subset at compile time, so that code can be generic over the subset it
+// enforces. ScalarsDef, XmlCharsDef, and AssignablesDef name the built-in ones; to name some
+// other subset, define an empty struct type whose Subset method returns it.
+type SubsetDef interface {
+	Subset() *Subset
+}
+
+// ScalarsDef is the SubsetDef for Scalars.
+type ScalarsDef struct{}
+
+// Subset returns Scalars.
+func (ScalarsDef) Subset() *Subset {
+	return Scalars
+}
+
+// XmlCharsDef is the SubsetDef for XmlChars.
+type XmlCharsDef struct{}
+
+// Subset returns XmlChars.
+func (XmlCharsDef) Subset() *Subset {
+	return XmlChars
+}
+
+// AssignablesDef is the SubsetDef for Assignables.
+type AssignablesDef struct{}
+
+// Subset returns Assignables.
+func (AssignablesDef) Subset() *Subset {
+	return Assignables
+}
+
+// A Validated holds a string which is known to be in the subset S names, like XmlString and
+// AssignableString but for any subset. The zero value is the empty string, which is valid.
+type Validated[S SubsetDef] struct {
+	s string
+}
+
+// NewValidated returns s as a Validated[S], or a *Violation if it is not valid for the
+// subset S names.
+func NewValidated[S SubsetDef](s string) (Validated[S], error) {
+	var def S
+	if v := def.Subset().FirstInvalidString(s); v != nil {
+		return Validated[S]{}, v
+	}
+	return Validated[S]{s: s}, nil
+}
+
+// UncheckedValidated is the equivalent of UncheckedXmlString for Validated.
+func UncheckedValidated[S SubsetDef](s string) Validated[S] {
+	return Validated[S]{s: s}
+}
+
+// String returns the underlying string.
+func (vs Validated[S]) String() string {
+	return vs.s
+}
+
+// MarshalText implements encoding.TextMarshaler.
+func (vs Validated[S]) MarshalText() ([]byte, error) {
+	return []byte(vs.s), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler, failing with a *Violation for text
+// which is not valid for the subset S names.
+func (vs *Validated[S]) UnmarshalText(text []byte) error {
+	var def S
+	if v := def.Subset().FirstInvalidUtf8(text); v != nil {
+		return v
+	}
+	vs.s = string(text)
+	return nil
+}
@@ -0,0 +1,216 @@
+package rfc9839
+
+import (
+	"encoding/json"
+	"errors"
+	"testing"
+	"unicode"
+)
+
+func TestXmlString(t *testing.T) {
+	var zero XmlString
+	if zero.String() != "" {
+		t.Error("zero XmlString should be empty")
+	}
+	for _, s := range []string{"", "hello", "tab\there\r\n", "\U0001FFFE"} {
+		x, err := NewXmlString(s)
+		if err != nil || x.String() != s {
+			t.Errorf("NewXmlString(%q) = %q, %v", s, x, err)
+		}
+	}
+	for _, s := range []string{"\x00", "ok\uFFFF", "bad\xff"} {
+		x, err := NewXmlString(s)
+		var v *Violation
+		if !errors.As(err, &v) || x != zero {
+			t.Errorf("NewXmlString(%q) should fail", s)
+		}
+	}
+}
+
+func TestAssignableString(t *testing.T) {
+	var zero AssignableString
+	if zero.String() != "" {
+		t.Error("zero AssignableString should be empty")
+	}
+	for _, s := range []string{"", "hello", "tab\there\r\n", "\U0010FFFD"} {
+		a, err := NewAssignableString(s)
+		if err != nil || a.String() != s {
+			t.Errorf("NewAssignableString(%q) = %q, %v", s, a, err)
+		}
+	}
+	for _, s := range []string{"\x00", "del\x7f", "\U0001FFFE", "bad\xff"} {
+		a, err := NewAssignableString(s)
+		var v *Violation
+		if !errors.As(err, &v) || a != zero {
+			t.Errorf("NewAssignableString(%q) should fail", s)
+		}
+	}
+}
+
+func TestUnchecked(t *testing.T) {
+	// the point is that nothing is checked, so even invalid text goes through
+	if x := UncheckedXmlString("a\x00"); x.String() != "a\x00" {
+		t.Errorf("UncheckedXmlString = %q", x)
+	}
+	if a := UncheckedAssignableString("a\x7f"); a.String() != "a\x7f" {
+		t.Errorf("UncheckedAssignableString = %q", a)
+	}
+	if vs := UncheckedValidated[XmlCharsDef]("\uFFFF"); vs.String() != "\uFFFF" {
+		t.Errorf("UncheckedValidated = %q", vs)
+	}
+	checked, _ := NewXmlString("same")
+	if UncheckedXmlString("same") != checked {
+		t.Error("UncheckedXmlString differs from NewXmlString for valid input")
+	}
+}
+
+func TestAssignableToXmlString(t *testing.T) {
+	if !Assignables.IsSubsetOf(XmlChars) {
+		t.Fatal("AssignableString.XmlString relies on Assignables being a subset of XmlChars")
+	}
+	a, err := NewAssignableString("café \U0010FFFD")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if x := a.XmlString(); x.String() != a.String() {
+		t.Errorf("XmlString() = %q, want %q", x, a)
+	}
+}
+
+func TestValidatedJson(t *testing.T) {
+	type config struct {
+		Title XmlString
+		Name  AssignableString
+	}
+	var c config
+	err := json.Unmarshal([]byte(`{"Title": "café\u007f", "Name": "Tim"}`), &c)
+	if err != nil || c.Title.String() != "café\x7f" || c.Name.String() != "Tim" {
+		t.Fatalf("Unmarshal = %+v, %v", c, err)
+	}
+	out, err := json.Marshal(c)
+	if err != nil || string(out) != "{\"Title\":\"café\x7f\",\"Name\":\"Tim\"}" {
+		t.Errorf("Marshal = %s, %v", out, err)
+	}
+
+	var v *Violation
+	err = json.Unmarshal([]byte(`{"Title": "ab\u0001"}`), &c)
+	if !errors.As(err, &v) || v.Offset != 2 {
+		t.Errorf("Unmarshal of a bad XmlString = %v", err)
+	}
+	err = json.Unmarshal([]byte(`{"Name": "del\u007f"}`), &c)
+	if !errors.As(err, &v) || v.Offset != 3 {
+		t.Errorf("Unmarshal of a bad AssignableString = %v", err)
+	}
+}
+
+// the validated types work as plain fields, and as optional and repeated ones, with no help
+func TestValidatedJsonFields(t *testing.T) {
+	type form struct {
+		Name     AssignableString
+		Nickname *AssignableString
+		Tags     []AssignableString
+		Notes    map[string]XmlString
+	}
+	var f form
+	err := json.Unmarshal([]byte(`{"Name": "Tim", "Tags": ["a", "b"], "Notes": {"k": "v"}}`), &f)
+	if err != nil || f.Name.String() != "Tim" || f.Nickname != nil || len(f.Tags) != 2 || f.Notes["k"].String() != "v" {
+		t.Fatalf("Unmarshal = %+v, %v", f, err)
+	}
+	err = json.Unmarshal([]byte(`{"Nickname": "TB"}`), &f)
+	if err != nil || f.Nickname == nil || f.Nickname.String() != "TB" {
+		t.Fatalf("Unmarshal of an optional field = %+v, %v", f, err)
+	}
+
+	var v *Violation
+	for _, in := range []string{`{"Nickname": "\u0000"}`, `{"Tags": ["ok", "\u007f"]}`, `{"Notes": {"k": "\u0001"}}`} {
+		if err := json.Unmarshal([]byte(in), &f); !errors.As(err, &v) {
+			t.Errorf("Unmarshal(%s) = %v, want a *Violation", in, err)
+		}
+	}
+}
+
+func TestValidatedBuilders(t *testing.T) {
+	var xb XmlStringBuilder
+	for _, r := range "café " {
+		if err := xb.WriteRune(r); err != nil {
+			t.Fatalf("WriteRune(%q) = %v", r, err)
+		}
+	}
+	if err := xb.WriteString("\U0001F600\t"); err != nil {
+		t.Fatalf("WriteString = %v", err)
+	}
+	var v *Violation
+	if err := xb.WriteString("ok\x00"); !errors.As(err, &v) || v.Offset != 13 || v.Index != 9 {
+		t.Errorf("WriteString = %v", err)
+	}
+	if err := xb.WriteRune(0xFFFE); !errors.As(err, &v) || v.Offset != 11 || v.Index != 7 || v.Width != 3 {
+		t.Errorf("WriteRune = %v", err)
+	}
+	if err := xb.WriteRune(0xD800); !errors.As(err, &v) || v.Problem != Surrogate {
+		t.Errorf("WriteRune = %v", err)
+	}
+	if got := xb.XmlString().String(); got != "café \U0001F600\t" {
+		t.Errorf("XmlString = %q", got)
+	}
+
+	var ab AssignableStringBuilder
+	if err := ab.WriteString("name"); err != nil {
+		t.Fatalf("WriteString = %v", err)
+	}
+	if err := ab.WriteRune(0x7F); !errors.As(err, &v) || v.Offset != 4 {
+		t.Errorf("WriteRune = %v", err)
+	}
+	if err := ab.WriteRune('!'); err != nil {
+		t.Errorf("WriteRune = %v", err)
+	}
+	if got := ab.AssignableString().String(); got != "name!" {
+		t.Errorf("AssignableString = %q", got)
+	}
+}
+
+// digitsDef is a SubsetDef for a subset defined outside the package.
+type digitsDef struct{}
+
+var onlyDigits = NewSubsetBuilder(Scalars).Name("Digits").DenyRange(0, unicode.MaxRune).AllowRange('0', '9').Build()
+
+func (digitsDef) Subset() *Subset {
+	return onlyDigits
+}
+
+// firstRune is generic over the subset its argument is known to be in.
+func firstRune[S SubsetDef](vs Validated[S]) rune {
+	for _, r := range vs.String() {
+		return r
+	}
+	return -1
+}
+
+func TestGenericValidated(t *testing.T) {
+	x, err := NewValidated[XmlCharsDef]("\U0001FFFE")
+	if err != nil || firstRune(x) != 0x1FFFE {
+		t.Errorf("NewValidated[XmlCharsDef] = %q, %v", x, err)
+	}
+	var v *Violation
+	if _, err := NewValidated[AssignablesDef]("\U0001FFFE"); !errors.As(err, &v) || v.Subset != Assignables {
+		t.Errorf("NewValidated[AssignablesDef] = %v", err)
+	}
+	if _, err := NewValidated[ScalarsDef]("\x00"); err != nil {
+		t.Errorf("NewValidated[ScalarsDef] = %v", err)
+	}
+	if _, err := NewValidated[digitsDef]("12a"); !errors.As(err, &v) || v.Offset != 2 {
+		t.Errorf("NewValidated[digitsDef] = %v", err)
+	}
+
+	var c struct {
+		Count Validated[digitsDef]
+	}
+	if err := json.Unmarshal([]byte(`{"Count": "42"}`), &c); err != nil || c.Count.String() != "42" {
+		t.Errorf("Unmarshal = %+v, %v", c, err)
+	}
+	if err := json.Unmarshal([]byte(`{"Count": "4.2"}`), &c); !errors.As(err, &v) {
+		t.Errorf("Unmarshal of a bad Validated = %v", err)
+	}
+	if out, err := json.Marshal(c); err != nil || string(out) != `{"Count":"42"}` {
+		t.Errorf("Marshal = %s, %v", out, err)
+	}
+}
@@ -0,0 +1,309 @@
+package rfc9839
+
+import (
+	"errors"
+	"fmt"
+	"unicode"
+	"unicode/utf8"
+)
+
+// A Violation describes a rune in some input which is not in a Subset, or a byte of invalid UTF-8.
+type Violation struct {
+	Offset    int     // byte offset of the violation in the input
+	Index     int     // index of the violation in the input, counting runes and invalid bytes
+	Width     int     // length in bytes of the violation
+	Rune      rune    // the offending rune, or utf8.RuneError for a byte of invalid UTF-8
+	Subset    *Subset // the subset which the rune is not in
+	Problem   Problem // why the rune is problematic, if it is one of the RFC 9839 categories
+	Line      int     // 1-based line number, only from the Locate methods, otherwise 0
+	Column    int     // 1-based column, counting runes and invalid bytes, only from the Locate methods
+	Forbidden bool    // the rune is in Subset, but the caller forbade it, as with CheckStringExcluding
+}
+
+func newViolation(sub *Subset, offset, index, width int, r rune) Violation {
+	v := Violation{Offset: offset, Index: index, Width: width, Rune: r, Subset: sub}
+	if v.BadUtf8() {
+		v.Problem = InvalidUtf8
+	} else {
+		v.Problem = Classify(r)
+	}
+	return v
+}
+
+// BadUtf8 reports whether the violation is a byte of invalid UTF-8, as opposed to a correctly
+// encoded rune which is not in the subset. This follows the utf8 package's convention of
+// reporting invalid bytes as utf8.RuneError with a width of 1.
+func (v *Violation) BadUtf8() bool {
+	return v.Rune == utf8.RuneError && v.Width == 1
+}
+
+func (v *Violation) Error() string {
+	return v.message(false)
+}
+
+// LabeledError is like Error, but follows the code point of a legacy control with its name,
+// and that of a private-use character with a label, as in "U+0007 BELL (legacy control)",
+// which is easier to triage than the bare number. It is separate from Error so that
+// messages which callers already match or log don't change.
+func (v *Violation) LabeledError() string {
+	return v.message(true)
+}
+
+func (v *Violation) message(labeled bool) string {
+	where := fmt.Sprintf("byte offset %d", v.Offset)
+	if v.Line > 0 {
+		where = fmt.Sprintf("line %d, column %d", v.Line, v.Column)
+	}
+	if v.BadUtf8() {
+		return fmt.Sprintf("rfc9839: invalid UTF-8 at %s", where)
+	}
+	if v.Forbidden {
+		return fmt.Sprintf("rfc9839: %s at %s is forbidden", v.describeRune(labeled), where)
+	}
+	subset := "the subset"
+	if v.Subset != nil {
+		subset = v.Subset.String()
+	}
+	what := v.describeRune(labeled)
+	if v.Problem == NotProblematic {
+		return fmt.Sprintf("rfc9839: %s at %s is not in %s", what, where, subset)
+	}
+	return fmt.Sprintf("rfc9839: %s (%s) at %s is not in %s", what, v.Problem, where, subset)
+}
+
+// describeRune returns the rune as U+XXXX, and if labeled, its label if that adds anything.
+func (v *Violation) describeRune(labeled bool) string {
+	what := fmt.Sprintf("U+%04X", v.Rune)
+	if !labeled {
+		return what
+	}
+	// the labels of the other problems only repeat the Problem
+	if label := RuneLabel(v.Rune); label != "" && (v.Problem == LegacyControl || v.Problem == NotProblematic) {
+		what += " " + label
+	}
+	return what
+}
+
+// FirstInvalidString returns a Violation describing the first rune in s which is not in the
+// subset, or the first byte of invalid UTF-8, or nil if s is valid. The Violation's Offset
+// and Width identify exactly the bytes to replace.
+func (sub *Subset) FirstInvalidString(s string) *Violation {
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		offset += width
+	}
+	return nil
+}
+
+// LocateInvalidString is like FirstInvalidString, but also fills in the Violation's Line and
+// Column, tracking them in the same pass, for reporting problems in large text files. Lines
+// end with newline, so that a carriage return before one counts as part of the line.
+func (sub *Subset) LocateInvalidString(s string) *Violation {
+	line, column := 1, 1
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			v.Line, v.Column = line, column
+			return &v
+		}
+		if r == '\n' {
+			line++
+			column = 1
+		} else {
+			column++
+		}
+		offset += width
+	}
+	return nil
+}
+
+// LocateInvalidUtf8 is the []byte equivalent of LocateInvalidString.
+func (sub *Subset) LocateInvalidUtf8(u []byte) *Violation {
+	line, column := 1, 1
+	offset := 0
+	for index := 0; offset < len(u); index++ {
+		r, width, ok := decodeInSubset(u[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			v.Line, v.Column = line, column
+			return &v
+		}
+		if r == '\n' {
+			line++
+			column = 1
+		} else {
+			column++
+		}
+		offset += width
+	}
+	return nil
+}
+
+// LastInvalidString is like FirstInvalidString, but scans backward from the end of s and
+// describes the last rune which is not in the subset, or the last byte of invalid UTF-8.
+func (sub *Subset) LastInvalidString(s string) *Violation {
+	offset := len(s)
+	for offset > 0 {
+		r, width := utf8.DecodeLastRuneInString(s[:offset])
+		offset -= width
+		if (r == utf8.RuneError && width == 1) || !subsetContains(sub, r) {
+			// RuneCountInString counts each invalid byte as one, just as Index does
+			v := newViolation(sub, offset, utf8.RuneCountInString(s[:offset]), width, r)
+			return &v
+		}
+	}
+	return nil
+}
+
+// FirstInvalidUtf8 is the []byte equivalent of FirstInvalidString. Its result distinguishes
+// all three outcomes in one pass: nil for valid input, a Violation whose BadUtf8 method
+// returns true for malformed encoding, or otherwise a Violation for a rune not in the subset.
+func (sub *Subset) FirstInvalidUtf8(u []byte) *Violation {
+	offset := 0
+	for index := 0; offset < len(u); index++ {
+		r, width, ok := decodeInSubset(u[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		offset += width
+	}
+	return nil
+}
+
+// SplitAtFirstInvalidString splits s, without copying, into the longest prefix which is valid
+// for the subset and the rest, which starts with the first problem. If s is valid, rest is
+// empty.
+func (sub *Subset) SplitAtFirstInvalidString(s string) (valid, rest string) {
+	index := indexInvalidString(s, sub)
+	if index < 0 {
+		return s, ""
+	}
+	return s[:index], s[index:]
+}
+
+// SplitAtFirstInvalidUtf8 is the []byte equivalent of SplitAtFirstInvalidString. Both
+// results share u's memory.
+func (sub *Subset) SplitAtFirstInvalidUtf8(u []byte) (valid, rest []byte) {
+	if v := sub.FirstInvalidUtf8(u); v != nil {
+		return u[:v.Offset], u[v.Offset:]
+	}
+	return u, u[len(u):]
+}
+
+// CheckString returns nil if s is valid for the subset, or otherwise a *Violation describing
+// the first problem, as an error.
+func (sub *Subset) CheckString(s string) error {
+	if v := sub.FirstInvalidString(s); v != nil {
+		return v
+	}
+	// a nil *Violation would make a non-nil error
+	return nil
+}
+
+// CheckUtf8 is the []byte equivalent of CheckString.
+func (sub *Subset) CheckUtf8(u []byte) error {
+	if v := sub.FirstInvalidUtf8(u); v != nil {
+		return v
+	}
+	return nil
+}
+
+// CheckStringExcluding checks that s is valid for the subset and also contains no runes in
+// forbidden, which may be one of the unicode package's tables, such as unicode.Co for the
+// private-use characters, or one built by the caller; a nil table forbids nothing. It returns
+// nil or a *Violation describing the first offending rune; for a rune which is rejected only
+// because it is forbidden, the Violation's Forbidden field is true.
+func (sub *Subset) CheckStringExcluding(s string, forbidden *unicode.RangeTable) error {
+	offset := 0
+	for index := 0; offset < len(s); index++ {
+		r, width, ok := decodeStringInSubset(s[offset:], sub)
+		if !ok {
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		if forbidden != nil && unicode.Is(forbidden, r) {
+			v := newViolation(sub, offset, index, width, r)
+			v.Forbidden = true
+			return &v
+		}
+		offset += width
+	}
+	return nil
+}
+
+// EnsureString returns s unchanged if it is valid for the subset, or a *Violation, so that
+// validation can be done in line: name, err := rfc9839.Assignables.EnsureString(raw).
+func (sub *Subset) EnsureString(s string) (string, error) {
+	if v := sub.FirstInvalidString(s); v != nil {
+		return "", v
+	}
+	return s, nil
+}
+
+// EnsureUtf8 is the []byte equivalent of EnsureString.
+func (sub *Subset) EnsureUtf8(u []byte) ([]byte, error) {
+	if v := sub.FirstInvalidUtf8(u); v != nil {
+		return nil, v
+	}
+	return u, nil
+}
+
+// AppendString appends s to dst and returns the extended buffer, if s is valid for the
+// subset. Otherwise it returns dst unchanged and a *Violation, so that a frame under
+// construction never holds invalid text.
+func (sub *Subset) AppendString(dst []byte, s string) ([]byte, error) {
+	if v := sub.FirstInvalidString(s); v != nil {
+		return dst, v
+	}
+	return append(dst, s...), nil
+}
+
+// AppendUtf8 is the []byte equivalent of AppendString.
+func (sub *Subset) AppendUtf8(dst, u []byte) ([]byte, error) {
+	if v := sub.FirstInvalidUtf8(u); v != nil {
+		return dst, v
+	}
+	return append(dst, u...), nil
+}
+
+// ErrEmpty is returned by CheckNonEmptyString for the empty string.
+var ErrEmpty = errors.New("rfc9839: empty string")
+
+// CheckNonEmptyString is for required fields: it returns ErrEmpty if s is empty, although
+// every subset considers the empty string valid, and otherwise nil or a *Violation.
+func (sub *Subset) CheckNonEmptyString(s string) error {
+	if s == "" {
+		return ErrEmpty
+	}
+	return sub.CheckString(s)
+}
+
+// FirstUtf8Error returns the byte offset of the first invalid UTF-8 in u, and the run of
+// bytes starting there which can't be decoded, for logging. It returns -1 and nil if u is
+// correctly encoded. Unlike the Subset methods, it is not concerned with which runes are present.
+func FirstUtf8Error(u []byte) (offset int, bad []byte) {
+	for offset < len(u) {
+		r, width := utf8.DecodeRune(u[offset:])
+		if r == utf8.RuneError && width == 1 {
+			end := offset + 1
+			for end < len(u) {
+				next, nextWidth := utf8.DecodeRune(u[end:])
+				if next != utf8.RuneError || nextWidth != 1 {
+					break
+				}
+				end++
+			}
+			return offset, u[offset:end]
+		}
+		offset += width
+	}
+	return -1, nil
+}
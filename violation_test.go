@@ -0,0 +1,302 @@
+package rfc9839
+
+import (
+	"errors"
+	"testing"
+	"unicode"
+	"unicode/utf8"
+)
+
+func TestViolationError(t *testing.T) {
+	tests := []struct {
+		v    Violation
+		want string
+	}{
+		{Violation{Offset: 3, Width: 1, Rune: 0, Subset: XmlChars, Problem: LegacyControl},
+			"rfc9839: U+0000 (legacy control) at byte offset 3 is not in XmlChars"},
+		{Violation{Offset: 3, Width: 2, Rune: 0xE9},
+			"rfc9839: U+00E9 at byte offset 3 is not in the subset"},
+		{Violation{Offset: 9, Width: 4, Rune: 0x10FFFF, Subset: Assignables, Problem: Noncharacter},
+			"rfc9839: U+10FFFF (noncharacter) at byte offset 9 is not in Assignables"},
+		{Violation{Offset: 7, Width: 1, Rune: 0xFFFD, Subset: Scalars, Problem: InvalidUtf8},
+			"rfc9839: invalid UTF-8 at byte offset 7"},
+		{Violation{Offset: 7, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl, Line: 2, Column: 3},
+			"rfc9839: U+0001 (legacy control) at line 2, column 3 is not in XmlChars"},
+		{Violation{Offset: 7, Width: 1, Rune: 0xFFFD, Subset: Scalars, Problem: InvalidUtf8, Line: 1, Column: 8},
+			"rfc9839: invalid UTF-8 at line 1, column 8"},
+	}
+	for _, test := range tests {
+		if got := test.v.Error(); got != test.want {
+			t.Errorf("Error() = %q, want %q", got, test.want)
+		}
+		if test.v.BadUtf8() != (test.v.Problem == InvalidUtf8) {
+			t.Errorf("BadUtf8() wrong for %q", test.want)
+		}
+	}
+}
+
+func TestClassify(t *testing.T) {
+	tests := []struct {
+		r    rune
+		want Problem
+	}{
+		{-1, OutOfRange},
+		{0, LegacyControl},
+		{'\t', NotProblematic},
+		{'\n', NotProblematic},
+		{'\r', NotProblematic},
+		{0x1F, LegacyControl},
+		{' ', NotProblematic},
+		{0x7E, NotProblematic},
+		{0x7F, LegacyControl},
+		{0x9F, LegacyControl},
+		{0xA0, NotProblematic},
+		{0xD7FF, NotProblematic},
+		{0xD800, Surrogate},
+		{0xDFFF, Surrogate},
+		{0xFDCF, NotProblematic},
+		{0xFDD0, Noncharacter},
+		{0xFDEF, Noncharacter},
+		{0xFDF0, NotProblematic},
+		{0xFFFD, NotProblematic},
+		{0xFFFE, Noncharacter},
+		{0x1FFFF, Noncharacter},
+		{0x10FFFF, Noncharacter},
+		{0x110000, OutOfRange},
+	}
+	for _, test := range tests {
+		if got := Classify(test.r); got != test.want {
+			t.Errorf("Classify(%x) = %v, want %v", test.r, got, test.want)
+		}
+	}
+	if Problem(-1).String() != "unknown problem" || Problem(99).String() != "unknown problem" {
+		t.Error("bad Problem should be unknown")
+	}
+}
+
+func TestFirstInvalid(t *testing.T) {
+	tests := []struct {
+		sub  *Subset
+		in   string
+		want *Violation
+	}{
+		{XmlChars, "", nil},
+		{XmlChars, "all good", nil},
+		{XmlChars, "ab\x01", &Violation{Offset: 2, Index: 2, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl}},
+		{XmlChars, "\u00e9\uFFFE\x00", &Violation{Offset: 2, Index: 1, Width: 3, Rune: 0xFFFE, Subset: XmlChars, Problem: Noncharacter}},
+		{XmlChars, "xy\xed\xba\xad", &Violation{Offset: 2, Index: 2, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+		{Assignables, "\U0001F600\U0001FFFF", &Violation{Offset: 4, Index: 1, Width: 4, Rune: 0x1FFFF, Subset: Assignables, Problem: Noncharacter}},
+		{Scalars, "\x00\x7f\u0085\U0010FFFF", nil},
+	}
+	for _, test := range tests {
+		for _, got := range []*Violation{test.sub.FirstInvalidString(test.in), test.sub.FirstInvalidUtf8([]byte(test.in))} {
+			if (got == nil) != (test.want == nil) || (got != nil && *got != *test.want) {
+				t.Errorf("FirstInvalid(%q) = %v, want %v", test.in, got, test.want)
+				continue
+			}
+			if got != nil {
+				// the span must land exactly on the offending rune or byte
+				r, width := utf8.DecodeRuneInString(test.in[got.Offset:])
+				if r != got.Rune || width != got.Width {
+					t.Errorf("FirstInvalid(%q) span doesn't match the input", test.in)
+				}
+			}
+		}
+	}
+}
+
+func TestLocateInvalid(t *testing.T) {
+	tests := []struct {
+		in   string
+		want *Violation
+	}{
+		{"", nil},
+		{"one\ntwo\r\nthree\n", nil},
+		{"\x00", &Violation{Offset: 0, Index: 0, Width: 1, Rune: 0, Subset: XmlChars, Problem: LegacyControl, Line: 1, Column: 1}},
+		{"one\nt\u00e9\x01", &Violation{Offset: 7, Index: 6, Width: 1, Rune: 1, Subset: XmlChars, Problem: LegacyControl, Line: 2, Column: 3}},
+		{"a\r\n\n\xffb", &Violation{Offset: 4, Index: 4, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8, Line: 3, Column: 1}},
+	}
+	for _, test := range tests {
+		for _, got := range []*Violation{XmlChars.LocateInvalidString(test.in), XmlChars.LocateInvalidUtf8([]byte(test.in))} {
+			if (got == nil) != (test.want == nil) || (got != nil && *got != *test.want) {
+				t.Errorf("LocateInvalid(%q) = %+v, want %+v", test.in, got, test.want)
+			}
+		}
+	}
+}
+
+func TestLastInvalidString(t *testing.T) {
+	tests := []struct {
+		in   string
+		want *Violation
+	}{
+		{"", nil},
+		{"all good", nil},
+		{"\x01ab\x02c", &Violation{Offset: 3, Index: 3, Width: 1, Rune: 2, Subset: XmlChars, Problem: LegacyControl}},
+		{"\x01\u00e9\uFFFEz", &Violation{Offset: 3, Index: 2, Width: 3, Rune: 0xFFFE, Subset: XmlChars, Problem: Noncharacter}},
+		{"\x00\xffx\u00e9", &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+		{"\x00\U0001F600", &Violation{Offset: 0, Index: 0, Width: 1, Rune: 0, Subset: XmlChars, Problem: LegacyControl}},
+	}
+	for _, test := range tests {
+		got := XmlChars.LastInvalidString(test.in)
+		if (got == nil) != (test.want == nil) || (got != nil && *got != *test.want) {
+			t.Errorf("LastInvalidString(%q) = %v, want %v", test.in, got, test.want)
+		}
+	}
+}
+
+func TestSplitAtFirstInvalid(t *testing.T) {
+	tests := []struct {
+		in, valid, rest string
+	}{
+		{"", "", ""},
+		{"all fine", "all fine", ""},
+		{"café\x00rest", "café", "\x00rest"},
+		{"\xffab", "", "\xffab"},
+		{"ok\uFFFE", "ok", "\uFFFE"},
+	}
+	for _, test := range tests {
+		valid, rest := Assignables.SplitAtFirstInvalidString(test.in)
+		if valid != test.valid || rest != test.rest {
+			t.Errorf("SplitAtFirstInvalidString(%q) = %q, %q, want %q, %q", test.in, valid, rest, test.valid, test.rest)
+		}
+		u := []byte(test.in)
+		validU, restU := Assignables.SplitAtFirstInvalidUtf8(u)
+		if string(validU) != test.valid || string(restU) != test.rest {
+			t.Errorf("SplitAtFirstInvalidUtf8(%q) = %q, %q, want %q, %q", test.in, validU, restU, test.valid, test.rest)
+		}
+		if len(u) > 0 && len(validU) > 0 && &validU[0] != &u[0] {
+			t.Errorf("SplitAtFirstInvalidUtf8(%q) copied its input", test.in)
+		}
+	}
+}
+
+func TestCheck(t *testing.T) {
+	for _, in := range []string{"", "fine", "tab\tnewline\n"} {
+		if err := XmlChars.CheckString(in); err != nil {
+			t.Errorf("CheckString(%q) = %v", in, err)
+		}
+		if err := XmlChars.CheckUtf8([]byte(in)); err != nil {
+			t.Errorf("CheckUtf8(%q) = %v", in, err)
+		}
+	}
+	want := Violation{Offset: 3, Index: 2, Width: 1, Rune: 0x1B, Subset: XmlChars, Problem: LegacyControl}
+	var v *Violation
+	if err := XmlChars.CheckString("\u00e9x\x1b"); !errors.As(err, &v) || *v != want {
+		t.Errorf("CheckString = %v, want %v", err, &want)
+	}
+	if err := XmlChars.CheckUtf8([]byte("\u00e9x\x1b")); !errors.As(err, &v) || *v != want {
+		t.Errorf("CheckUtf8 = %v, want %v", err, &want)
+	}
+}
+
+func TestCheckStringExcluding(t *testing.T) {
+	emoji := &unicode.RangeTable{R32: []unicode.Range32{{Lo: 0x1F300, Hi: 0x1FAFF, Stride: 1}}}
+	tests := []struct {
+		in        string
+		forbidden *unicode.RangeTable
+		offset    int
+		byCaller  bool // rejected only because it is forbidden
+	}{
+		{"plain text", emoji, -1, false},
+		{"smile \U0001F600", emoji, 6, true},
+		{"nul\x00 \U0001F600", emoji, 3, false},
+		{"private \uE000", unicode.Co, 8, true},
+		{"\U000F0000", unicode.Co, 0, true},
+		{"\U0001F600", unicode.Co, -1, false},
+		{"\U0001F600", nil, -1, false},
+		{"nul\x00", nil, 3, false},
+	}
+	for _, test := range tests {
+		err := XmlChars.CheckStringExcluding(test.in, test.forbidden)
+		if test.offset < 0 {
+			if err != nil {
+				t.Errorf("CheckStringExcluding(%q) = %v", test.in, err)
+			}
+			continue
+		}
+		var v *Violation
+		if !errors.As(err, &v) || v.Offset != test.offset || v.Forbidden != test.byCaller || v.Subset != XmlChars {
+			t.Errorf("CheckStringExcluding(%q) = %v, want offset %d", test.in, err, test.offset)
+		}
+	}
+
+	err := XmlChars.CheckStringExcluding("private \uE000", unicode.Co)
+	if got, want := err.Error(), "rfc9839: U+E000 at byte offset 8 is forbidden"; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+}
+
+func TestEnsure(t *testing.T) {
+	name, err := Assignables.EnsureString("Tim")
+	if err != nil || name != "Tim" {
+		t.Errorf("EnsureString = %q, %v", name, err)
+	}
+	u := []byte("Bray")
+	got, err := Assignables.EnsureUtf8(u)
+	if err != nil || &got[0] != &u[0] {
+		t.Errorf("EnsureUtf8 should return its input, got %q, %v", got, err)
+	}
+	var v *Violation
+	if name, err = Assignables.EnsureString("T\x7fm"); !errors.As(err, &v) || name != "" || v.Offset != 1 {
+		t.Errorf("EnsureString = %q, %v", name, err)
+	}
+	if got, err = XmlChars.EnsureUtf8([]byte("B\x01ay")); !errors.As(err, &v) || got != nil || v.Offset != 1 {
+		t.Errorf("EnsureUtf8 = %q, %v", got, err)
+	}
+}
+
+func TestAppendString(t *testing.T) {
+	frame := []byte{0x01, 0x00}
+	frame, err := Assignables.AppendString(frame, "héllo")
+	if err != nil || string(frame) != "\x01\x00héllo" {
+		t.Fatalf("AppendString = %q, %v", frame, err)
+	}
+	frame, err = Assignables.AppendUtf8(frame, []byte(" world"))
+	if err != nil || string(frame) != "\x01\x00héllo world" {
+		t.Fatalf("AppendUtf8 = %q, %v", frame, err)
+	}
+	var v *Violation
+	got, err := Assignables.AppendString(frame, "bad\x00")
+	if !errors.As(err, &v) || v.Offset != 3 || string(got) != string(frame) {
+		t.Errorf("AppendString of bad text = %q, %v", got, err)
+	}
+	got, err = Assignables.AppendUtf8(frame, []byte("\xff"))
+	if !errors.As(err, &v) || !v.BadUtf8() || string(got) != string(frame) {
+		t.Errorf("AppendUtf8 of bad text = %q, %v", got, err)
+	}
+}
+
+func TestCheckNonEmptyString(t *testing.T) {
+	if err := XmlChars.CheckNonEmptyString(""); err != ErrEmpty {
+		t.Errorf("CheckNonEmptyString(\"\") = %v, want ErrEmpty", err)
+	}
+	if err := XmlChars.CheckNonEmptyString("x"); err != nil {
+		t.Errorf("CheckNonEmptyString(\"x\") = %v", err)
+	}
+	var v *Violation
+	if err := XmlChars.CheckNonEmptyString("\x00"); !errors.As(err, &v) || v.Offset != 0 {
+		t.Errorf("CheckNonEmptyString(\"\\x00\") = %v", err)
+	}
+}
+
+func TestFirstUtf8Error(t *testing.T) {
+	tests := []struct {
+		in     string
+		offset int
+		bad    string
+	}{
+		{"", -1, ""},
+		{"valid \x00 \uFFFD \U0010FFFF", -1, ""},
+		{"ab\xffcd", 2, "\xff"},
+		{"ab\xed\xba\xadcd\xff", 2, "\xed\xba\xad"},
+		{"\xe2\x82", 0, "\xe2\x82"},
+		{"x\xe2\x82\xac\xc3", 4, "\xc3"},
+	}
+	for _, test := range tests {
+		offset, bad := FirstUtf8Error([]byte(test.in))
+		if offset != test.offset || string(bad) != test.bad {
+			t.Errorf("FirstUtf8Error(%q) = %d, %q, want %d, %q", test.in, offset, bad, test.offset, test.bad)
+		}
+	}
+}
@@ -0,0 +1,86 @@
+package rfc9839
+
+import (
+	"fmt"
+	"io"
+	"unicode/utf8"
+)
+
+// A ValidatingWriter passes bytes through to an underlying io.Writer, checking them against a
+// subset as they go. It never passes on the bytes of a violation or anything after them. The
+// bytes of a rune which is split between calls to Write are held back until it is complete.
+type ValidatingWriter struct {
+	w       io.Writer
+	pv      PartialValidator
+	pending []byte
+	err     error
+}
+
+// NewValidatingWriter returns a ValidatingWriter which checks bytes against sub before
+// writing them to w.
+func NewValidatingWriter(w io.Writer, sub *Subset) *ValidatingWriter {
+	return &ValidatingWriter{w: w, pv: PartialValidator{sub: sub}, pending: make([]byte, 0, utf8.UTFMax)}
+}
+
+// Write writes p to the underlying writer. If p contains a violation, Write writes only the
+// bytes before it, and returns their number along with a *Violation whose Offset counts from
+// the first byte ever written. Every later call returns the same *Violation.
+func (vw *ValidatingWriter) Write(p []byte) (int, error) {
+	if vw.err != nil {
+		return 0, vw.err
+	}
+	n := 0
+	if len(vw.pending) > 0 {
+		// complete the rune held back from an earlier call
+		for n < len(p) && !utf8.FullRune(vw.pending) {
+			vw.pending = append(vw.pending, p[n])
+			n++
+		}
+		if !utf8.FullRune(vw.pending) {
+			return n, nil
+		}
+		if progress := vw.pv.Feed(vw.pending); progress.Violation != nil {
+			vw.err = progress.Violation
+			return 0, vw.err
+		}
+		if _, err := vw.w.Write(vw.pending); err != nil {
+			return 0, err
+		}
+		vw.pending = vw.pending[:0]
+	}
+	progress := vw.pv.Feed(p[n:])
+	written, err := vw.w.Write(p[n : n+progress.Consumed])
+	if err != nil {
+		return n + written, err
+	}
+	if progress.Violation != nil {
+		vw.err = progress.Violation
+		return n + written, vw.err
+	}
+	vw.pending = append(vw.pending, p[n+written:]...)
+	return len(p), nil
+}
+
+// Close reports a *Violation if the bytes written end part way through a rune. It does not
+// close the underlying writer.
+func (vw *ValidatingWriter) Close() error {
+	if vw.err == nil && len(vw.pending) > 0 {
+		if v := vw.pv.Finish(vw.pending); v != nil {
+			vw.err = v
+		}
+		vw.pending = vw.pending[:0]
+	}
+	return vw.err
+}
+
+// Fprintf formats according to format, like fmt.Fprintf, and writes the result to w only if
+// it is entirely valid for the subset, so that a log line never goes out partly written. If
+// it is not valid, Fprintf writes nothing and returns a *Violation whose Offset counts from
+// the start of the formatted text.
+func (sub *Subset) Fprintf(w io.Writer, format string, args ...any) (int, error) {
+	formatted := fmt.Appendf(nil, format, args...)
+	if v := sub.FirstInvalidUtf8(formatted); v != nil {
+		return 0, v
+	}
+	return w.Write(formatted)
+}
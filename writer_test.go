@@ -0,0 +1,66 @@
+package rfc9839
+
+import (
+	"bytes"
+	"errors"
+	"testing"
+)
+
+func TestValidatingWriter(t *testing.T) {
+	var buf bytes.Buffer
+	vw := NewValidatingWriter(&buf, Assignables)
+	euro := []byte("€")
+	for _, p := range [][]byte{[]byte("price: "), euro[:1], euro[1:], []byte("5\n")} {
+		if n, err := vw.Write(p); n != len(p) || err != nil {
+			t.Fatalf("Write(%q) = %d, %v", p, n, err)
+		}
+	}
+	if err := vw.Close(); err != nil || buf.String() != "price: €5\n" {
+		t.Fatalf("Close = %v, wrote %q", err, buf.String())
+	}
+
+	buf.Reset()
+	vw = NewValidatingWriter(&buf, Assignables)
+	vw.Write([]byte("abc"))
+	n, err := vw.Write([]byte("de\x7ffg"))
+	var v *Violation
+	if n != 2 || !errors.As(err, &v) || v.Offset != 5 || buf.String() != "abcde" {
+		t.Fatalf("Write = %d, %v, wrote %q", n, err, buf.String())
+	}
+	if n, again := vw.Write([]byte("more")); n != 0 || again != err {
+		t.Errorf("Write after a violation = %d, %v", n, again)
+	}
+
+	// a violation in a rune begun by an earlier Write
+	buf.Reset()
+	vw = NewValidatingWriter(&buf, Assignables)
+	vw.Write([]byte{'a', 0xEF, 0xBF})
+	if buf.String() != "a" {
+		t.Errorf("wrote %q, want the incomplete rune held back", buf.String())
+	}
+	n, err = vw.Write([]byte{0xBF, 'b'})
+	if n != 0 || !errors.As(err, &v) || v.Offset != 1 || v.Rune != 0xFFFF || buf.String() != "a" {
+		t.Errorf("Write = %d, %v, wrote %q", n, err, buf.String())
+	}
+
+	// input which stops part way through a rune
+	vw = NewValidatingWriter(&buf, Assignables)
+	vw.Write(euro[:2])
+	if err := vw.Close(); !errors.As(err, &v) || !v.BadUtf8() {
+		t.Errorf("Close = %v", err)
+	}
+}
+
+func TestFprintf(t *testing.T) {
+	var buf bytes.Buffer
+	n, err := Assignables.Fprintf(&buf, "user %q logged in from %s\n", "tim", "café")
+	if err != nil || buf.String() != "user \"tim\" logged in from café\n" || n != buf.Len() {
+		t.Fatalf("Fprintf = %d, %v, wrote %q", n, err, buf.String())
+	}
+	buf.Reset()
+	n, err = Assignables.Fprintf(&buf, "user %s logged in\n", "ti\x1b[2Jm")
+	var v *Violation
+	if !errors.As(err, &v) || v.Offset != 7 || v.Rune != 0x1B || n != 0 || buf.Len() != 0 {
+		t.Errorf("Fprintf of a bad argument = %d, %v, wrote %q", n, err, buf.String())
+	}
+}
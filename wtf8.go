@@ -0,0 +1,40 @@
+package rfc9839
+
+// FirstInvalidWtf8 is like FirstInvalidUtf8, but for WTF-8, the superset of UTF-8 which also
+// encodes lone surrogates, as found in strings from JavaScript engines and in Windows file
+// names. A surrogate encoded as three bytes is decoded rather than treated as invalid UTF-8,
+// and since no subset contains surrogates, is reported as a Violation whose Rune is the
+// surrogate, whose Width is 3, and whose Problem is Surrogate.
+func (sub *Subset) FirstInvalidWtf8(u []byte) *Violation {
+	offset := 0
+	for index := 0; offset < len(u); index++ {
+		r, width, ok := decodeInSubset(u[offset:], sub)
+		if !ok {
+			if surrogate, isSurrogate := decodeWtf8Surrogate(u[offset:]); isSurrogate {
+				r, width = surrogate, 3
+			}
+			v := newViolation(sub, offset, index, width, r)
+			return &v
+		}
+		offset += width
+	}
+	return nil
+}
+
+// CheckWtf8 returns nil if u is valid for the subset, or otherwise a *Violation describing
+// the first problem, as FirstInvalidWtf8 finds it.
+func (sub *Subset) CheckWtf8(u []byte) error {
+	if v := sub.FirstInvalidWtf8(u); v != nil {
+		return v
+	}
+	return nil
+}
+
+// decodeWtf8Surrogate decodes the surrogate, U+D800 through U+DFFF, whose three-byte encoding
+// starts u, if there is one.
+func decodeWtf8Surrogate(u []byte) (rune, bool) {
+	if len(u) < 3 || u[0] != 0xED || u[1] < 0xA0 || u[1] > 0xBF || u[2] < 0x80 || u[2] > 0xBF {
+		return 0, false
+	}
+	return 0xD000 | rune(u[1]&0x3F)<<6 | rune(u[2]&0x3F), true
+}
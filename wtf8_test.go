@@ -0,0 +1,41 @@
+package rfc9839
+
+import (
+	"errors"
+	"testing"
+)
+
+func TestFirstInvalidWtf8(t *testing.T) {
+	tests := []struct {
+		in   string
+		want *Violation
+	}{
+		{"", nil},
+		{"café \U0001F600", nil},
+		{"ab\xed\xa0\x80", &Violation{Offset: 2, Index: 2, Width: 3, Rune: 0xD800, Subset: XmlChars, Problem: Surrogate}},
+		{"é\xed\xbf\xbfz", &Violation{Offset: 2, Index: 1, Width: 3, Rune: 0xDFFF, Subset: XmlChars, Problem: Surrogate}},
+		// a paired surrogate is still reported, at its first half
+		{"\xed\xa0\xbd\xed\xb8\x80", &Violation{Offset: 0, Index: 0, Width: 3, Rune: 0xD83D, Subset: XmlChars, Problem: Surrogate}},
+		// a truncated surrogate is just invalid UTF-8
+		{"a\xed\xa0", &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+		{"a\x00", &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0, Subset: XmlChars, Problem: LegacyControl}},
+		{"a\xff", &Violation{Offset: 1, Index: 1, Width: 1, Rune: 0xFFFD, Subset: XmlChars, Problem: InvalidUtf8}},
+	}
+	for _, test := range tests {
+		got := XmlChars.FirstInvalidWtf8([]byte(test.in))
+		if (got == nil) != (test.want == nil) || (got != nil && *got != *test.want) {
+			t.Errorf("FirstInvalidWtf8(%q) = %v, want %v", test.in, got, test.want)
+		}
+		err := XmlChars.CheckWtf8([]byte(test.in))
+		var v *Violation
+		if errors.As(err, &v) != (test.want != nil) {
+			t.Errorf("CheckWtf8(%q) = %v", test.in, err)
+		}
+	}
+	for r := rune(0xD800); r <= 0xDFFF; r++ {
+		u := []byte{0xED, byte(0x80 | (r>>6)&0x3F), byte(0x80 | r&0x3F)}
+		if got, ok := decodeWtf8Surrogate(u); !ok || got != r {
+			t.Fatalf("decodeWtf8Surrogate(% X) = %X, %t", u, got, ok)
+		}
+	}
+}
@@ -0,0 +1,16 @@
+package rfc9839
+
+// ValidXmlAttribute reports whether s can be placed verbatim, with no escaping, in an XML
+// attribute value delimited by quote: that is, whether it's in XmlChars and contains no
+// '<', '&', or quote.
+func ValidXmlAttribute(s string, quote rune) bool {
+	index := 0
+	for index < len(s) {
+		r, width, ok := decodeStringInSubset(s[index:], XmlChars)
+		if !ok || r == '<' || r == '&' || r == quote {
+			return false
+		}
+		index += width
+	}
+	return true
+}
@@ -0,0 +1,32 @@
+package rfc9839
+
+import (
+	"testing"
+)
+
+func TestValidXmlAttribute(t *testing.T) {
+	tests := []struct {
+		in    string
+		quote rune
+		want  bool
+	}{
+		{"", '"', true},
+		{"plain value", '"', true},
+		{"it's", '"', true},
+		{"it's", '\'', false},
+		{`say "hi"`, '"', false},
+		{`say "hi"`, '\'', true},
+		{"a > b", '"', true},
+		{"a < b", '"', false},
+		{"AT&T", '"', false},
+		{"tab\tand\nnewline", '"', true},
+		{"nul\x00", '"', false},
+		{"bad\xff", '"', false},
+		{"\U0001F600", '"', true},
+	}
+	for _, test := range tests {
+		if got := ValidXmlAttribute(test.in, test.quote); got != test.want {
+			t.Errorf("ValidXmlAttribute(%q, %q) = %t, want %t", test.in, test.quote, got, test.want)
+		}
+	}
+}